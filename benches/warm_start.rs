@@ -0,0 +1,41 @@
+//! Iterations and time for a target moving along a circle, cold vs warm start.
+//!
+//! Run with `cargo bench --bench warm_start`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use fabrik::{Chain, ChainConfig, Vec2};
+
+const FRAMES: usize = 10_000;
+
+fn run(warm_start: bool) -> (usize, f64) {
+    let config = ChainConfig {
+        tolerance: 0.05,
+        max_iterations: 50,
+        warm_start,
+        ..Default::default()
+    };
+    let mut chain = Chain::new(Vec2::ZERO, &config);
+    let radius = chain.total_length() * 0.6;
+
+    let mut iterations = 0;
+    let start = Instant::now();
+    for frame in 0..FRAMES {
+        let angle = frame as f32 * 0.02;
+        let target = Vec2::from_angle(angle) * radius + Vec2::new(0.0, radius * 0.3);
+        iterations += black_box(chain.solve(target)).iterations;
+    }
+    (iterations, start.elapsed().as_secs_f64())
+}
+
+fn main() {
+    for (name, warm_start) in [("cold", false), ("warm", true)] {
+        let (iterations, secs) = run(warm_start);
+        println!(
+            "{name}: {:.2} iterations/frame, {:.2} µs/frame",
+            iterations as f64 / FRAMES as f64,
+            secs * 1e6 / FRAMES as f64
+        );
+    }
+}
@@ -1,5 +1,10 @@
 use eframe::egui::{self, Color32, Pos2, Stroke};
-use fabrik::{Chain, ChainConfig, Vec2};
+use fabrik::{Chain, ChainConfig, Terrain, Transform2, Vec2, Walker};
+
+/// Body speed of the walker demo, in pixels per second
+const WALKER_SPEED: f32 = 160.0;
+/// Height of the walker's body above the ground
+const WALKER_HEIGHT: f32 = 80.0;
 
 fn main() -> eframe::Result<()> {
     let options = eframe::NativeOptions {
@@ -18,6 +23,7 @@ struct App {
     config: ChainConfig,
     chain: Chain,
     target: Vec2,
+    walker: Option<Walker>,
 }
 
 impl App {
@@ -30,7 +36,113 @@ impl App {
             config,
             chain,
             target: Vec2::new(512.0, 300.0),
+            walker: None,
+        }
+    }
+
+    /// Walker demo: the body walks toward the mouse over rolling ground
+    fn show_walker(
+        &mut self,
+        ctx: &egui::Context,
+        response: &egui::Response,
+        painter: &egui::Painter,
+    ) {
+        let rect = response.rect;
+        let terrain = ground(rect);
+        let dt = ctx.input(|i| i.stable_dt).min(0.1);
+        let Some(walker) = self.walker.as_mut() else {
+            return;
+        };
+
+        // Follow the mouse horizontally, riding at a fixed height over the ground
+        if let Some(pos) = response.hover_pos() {
+            self.target = Vec2::new(pos.x, pos.y);
+        }
+        let body = walker.body.translation;
+        let goal_x = self
+            .target
+            .x
+            .clamp(rect.left() + 100.0, rect.right() - 100.0);
+        let x = body.x + (goal_x - body.x).clamp(-WALKER_SPEED * dt, WALKER_SPEED * dt);
+        let y = terrain.height_at(x).unwrap_or(body.y + WALKER_HEIGHT) - WALKER_HEIGHT;
+        walker.set_body(Transform2::from_translation(Vec2::new(x, y)));
+        walker.terrain = Some(terrain);
+        walker.update(dt);
+
+        // Draw background and ground
+        painter.rect_filled(rect, 0.0, Color32::from_rgb(25, 25, 38));
+        if let Some(Terrain::Polyline(points)) = &walker.terrain {
+            let points = points.iter().map(|p| Pos2::new(p.x, p.y)).collect();
+            painter.add(egui::Shape::line(
+                points,
+                Stroke::new(2.0, Color32::from_rgb(90, 110, 90)),
+            ));
+        }
+
+        // Body and legs
+        for leg in &walker.legs {
+            draw_chain(painter, &leg.chain.joints);
         }
+        let body = walker.body.translation;
+        painter.rect_filled(
+            egui::Rect::from_center_size(Pos2::new(body.x, body.y), egui::vec2(110.0, 24.0)),
+            8.0,
+            Color32::from_rgb(200, 90, 120),
+        );
+    }
+}
+
+fn new_walker(body: Vec2) -> Walker {
+    let mut walker = Walker::new(Transform2::from_translation(body));
+    for (i, x) in [-45.0, -15.0, 15.0, 45.0].into_iter().enumerate() {
+        let leg = Chain::with_lengths(Vec2::ZERO, vec![50.0, 50.0, 40.0], 0.5, 20);
+        let home = Vec2::new(x * 2.0, WALKER_HEIGHT);
+        walker.add_leg(leg, Vec2::new(x, 0.0), home, i % 2);
+    }
+    walker
+}
+
+/// Rolling ground across the canvas
+fn ground(rect: egui::Rect) -> Terrain {
+    let base = rect.bottom() - 60.0;
+    let points = (0..=32)
+        .map(|i| {
+            let x = rect.left() + rect.width() * i as f32 / 32.0;
+            Vec2::new(x, base - 25.0 * (x * 0.012).sin())
+        })
+        .collect();
+    Terrain::Polyline(points)
+}
+
+fn draw_chain(painter: &egui::Painter, joints: &[Vec2]) {
+    let n = joints.len();
+
+    // Segments
+    for i in 0..n - 1 {
+        let t = i as f32 / (n - 1) as f32;
+        let color = Color32::from_rgb(
+            (50.0 + 150.0 * t) as u8,
+            (150.0 - 75.0 * t) as u8,
+            (230.0 - 130.0 * t) as u8,
+        );
+        painter.line_segment(
+            [
+                Pos2::new(joints[i].x, joints[i].y),
+                Pos2::new(joints[i + 1].x, joints[i + 1].y),
+            ],
+            Stroke::new(3.0, color),
+        );
+    }
+
+    // Joints
+    for (i, joint) in joints.iter().enumerate() {
+        let t = i as f32 / (n - 1) as f32;
+        let color = Color32::from_rgb(
+            (75.0 + 180.0 * t) as u8,
+            (180.0 - 100.0 * t) as u8,
+            (255.0 - 150.0 * t) as u8,
+        );
+        painter.circle_filled(Pos2::new(joint.x, joint.y), 6.0, color);
     }
 }
 
@@ -68,6 +180,14 @@ impl eframe::App for App {
                 if ui.button("Reset").clicked() {
                     self.config = ChainConfig::default();
                     self.chain.rebuild(&self.config);
+                    self.walker = None;
+                }
+
+                ui.separator();
+
+                let mut walking = self.walker.is_some();
+                if ui.checkbox(&mut walking, "Walker").changed() {
+                    self.walker = walking.then(|| new_walker(self.target));
                 }
             });
 
@@ -80,6 +200,11 @@ impl eframe::App for App {
 
             let rect = response.rect;
 
+            if self.walker.is_some() {
+                self.show_walker(ctx, &response, &painter);
+                return;
+            }
+
             // Update origin to center-bottom of canvas
             let origin = Vec2::new(rect.center().x, rect.bottom() - 100.0);
             self.chain.set_origin(origin);
@@ -93,7 +218,16 @@ impl eframe::App for App {
             self.chain.solve(self.target);
 
             // Draw background
-            painter.rect_filled(rect, 0.0, Color32::from_rgb(25, 25, 38));
+            let background = Color32::from_rgb(25, 25, 38);
+            painter.rect_filled(rect, 0.0, background);
+
+            // Shade reachable area
+            let base = Pos2::new(origin.x, origin.y);
+            let (inner, outer) = self.chain.reach_radii();
+            painter.circle_filled(base, outer, Color32::from_rgb(32, 34, 52));
+            if inner > 0.0 {
+                painter.circle_filled(base, inner, background);
+            }
 
             // Draw target
             painter.circle_filled(
@@ -108,36 +242,7 @@ impl eframe::App for App {
             );
 
             // Draw chain
-            let joints = &self.chain.joints;
-            let n = joints.len();
-
-            // Segments
-            for i in 0..n - 1 {
-                let t = i as f32 / (n - 1) as f32;
-                let color = Color32::from_rgb(
-                    (50.0 + 150.0 * t) as u8,
-                    (150.0 - 75.0 * t) as u8,
-                    (230.0 - 130.0 * t) as u8,
-                );
-                painter.line_segment(
-                    [
-                        Pos2::new(joints[i].x, joints[i].y),
-                        Pos2::new(joints[i + 1].x, joints[i + 1].y),
-                    ],
-                    Stroke::new(3.0, color),
-                );
-            }
-
-            // Joints
-            for (i, joint) in joints.iter().enumerate() {
-                let t = i as f32 / (n - 1) as f32;
-                let color = Color32::from_rgb(
-                    (75.0 + 180.0 * t) as u8,
-                    (180.0 - 100.0 * t) as u8,
-                    (255.0 - 150.0 * t) as u8,
-                );
-                painter.circle_filled(Pos2::new(joint.x, joint.y), 6.0, color);
-            }
+            draw_chain(&painter, &self.chain.joints);
         });
 
         // Request continuous repaints for smooth updates
@@ -0,0 +1,95 @@
+//! Weighted blending of several end-effector targets with eased weight changes.
+
+use crate::Vec2;
+
+/// Blends weighted targets into a single goal.
+///
+/// Targets are matched by their index in the slice passed to [`update`](Self::update).
+/// Weights move linearly toward the requested value over `blend_time` seconds, and a
+/// target that disappears from the slice fades out from its last known position.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetBlend {
+    pub blend_time: f32,
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Slot {
+    position: Vec2,
+    weight: f32,
+}
+
+impl TargetBlend {
+    pub fn new(blend_time: f32) -> Self {
+        Self {
+            blend_time,
+            slots: Vec::new(),
+        }
+    }
+
+    /// Advance weights by `dt` seconds toward those in `targets` and return the blended goal,
+    /// or `None` while every weight is zero
+    pub fn update(&mut self, targets: &[(Vec2, f32)], dt: f32) -> Option<Vec2> {
+        if self.slots.len() < targets.len() {
+            self.slots.resize(
+                targets.len(),
+                Slot {
+                    position: Vec2::ZERO,
+                    weight: 0.0,
+                },
+            );
+        }
+
+        // Nothing to ease from: adopt the requested weights at once
+        let idle = self.goal().is_none();
+        let step = if self.blend_time > 0.0 && !idle {
+            dt / self.blend_time
+        } else {
+            f32::INFINITY
+        };
+
+        for (i, slot) in self.slots.iter_mut().enumerate() {
+            let wanted = match targets.get(i) {
+                Some(&(position, weight)) => {
+                    slot.position = position;
+                    weight.max(0.0)
+                }
+                None => 0.0,
+            };
+            slot.weight += (wanted - slot.weight).clamp(-step, step);
+        }
+
+        // Drop trailing slots that have fully faded out
+        while self.slots.len() > targets.len() && self.slots.last().is_some_and(|s| s.weight == 0.0)
+        {
+            self.slots.pop();
+        }
+
+        self.goal()
+    }
+
+    /// Blended goal for the current weights, without advancing time
+    pub fn goal(&self) -> Option<Vec2> {
+        let total: f32 = self.slots.iter().map(|s| s.weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let sum = self
+            .slots
+            .iter()
+            .fold(Vec2::ZERO, |acc, s| acc + s.position * s.weight);
+        Some(sum / total)
+    }
+
+    /// Current (eased) weight of each target slot
+    pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
+        self.slots.iter().map(|s| s.weight)
+    }
+
+    /// Forget all targets and weights
+    pub fn clear(&mut self) {
+        self.slots.clear();
+    }
+}
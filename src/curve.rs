@@ -0,0 +1,106 @@
+//! Render helpers: a smooth spline through the joints and a tapered ribbon mesh.
+
+use crate::{Chain, Vec2};
+
+/// Triangle mesh of a ribbon, two vertices per curve point (left, then right side)
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ribbon {
+    pub vertices: Vec<Vec2>,
+    /// `x` runs from `0` at the base to `1` at the tip by arc length, `y` is `0` on the
+    /// left edge and `1` on the right
+    pub uvs: Vec<Vec2>,
+    /// Counter-clockwise triangles in `y`-up space (clockwise on screen), three per triangle
+    pub indices: Vec<u32>,
+}
+
+/// Uniform Catmull-Rom spline through `points`, `samples` points per span plus the last
+/// point. The curve passes through every input point; the ends are extrapolated linearly.
+pub fn catmull_rom(points: &[Vec2], samples: usize) -> Vec<Vec2> {
+    let samples = samples.max(1);
+    let Some(&last) = points.last() else {
+        return Vec::new();
+    };
+    if points.len() < 2 {
+        return vec![last];
+    }
+
+    let n = points.len();
+    let at = |i: isize| match i {
+        -1 => points[0] * 2.0 - points[1],
+        i if i as usize >= n => points[n - 1] * 2.0 - points[n - 2],
+        i => points[i as usize],
+    };
+
+    let mut curve = Vec::with_capacity((n - 1) * samples + 1);
+    for i in 0..n as isize - 1 {
+        let (p0, p1, p2, p3) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+        for k in 0..samples {
+            let t = k as f32 / samples as f32;
+            let (t2, t3) = (t * t, t * t * t);
+            curve.push(
+                (p1 * 2.0
+                    + (p2 - p0) * t
+                    + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * t2
+                    + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * t3)
+                    * 0.5,
+            );
+        }
+    }
+    curve.push(last);
+    curve
+}
+
+/// Ribbon along a polyline; `width(t)` gives the full width at arc-length fraction `t`
+/// (`0` at the first point, `1` at the last)
+pub fn ribbon(points: &[Vec2], width: impl Fn(f32) -> f32) -> Ribbon {
+    let mut distances = Vec::with_capacity(points.len());
+    let mut total = 0.0;
+    for (i, &p) in points.iter().enumerate() {
+        if i > 0 {
+            total += p.distance(points[i - 1]);
+        }
+        distances.push(total);
+    }
+
+    let mut mesh = Ribbon::default();
+    let mut normal = Vec2::X;
+    for (i, &p) in points.iter().enumerate() {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(points.len() - 1)];
+        let tangent = (next - prev).normalize();
+        // Keep the last normal where consecutive points coincide
+        if tangent != Vec2::ZERO {
+            normal = tangent.perp();
+        }
+
+        let t = if total > 0.0 {
+            distances[i] / total
+        } else {
+            0.0
+        };
+        let half = normal * (width(t) * 0.5);
+        mesh.vertices.extend([p + half, p - half]);
+        mesh.uvs.extend([Vec2::new(t, 0.0), Vec2::new(t, 1.0)]);
+    }
+
+    for i in 0..points.len().saturating_sub(1) as u32 {
+        let (l0, r0, l1, r1) = (2 * i, 2 * i + 1, 2 * i + 2, 2 * i + 3);
+        mesh.indices.extend([l0, r0, l1, l1, r0, r1]);
+    }
+    mesh
+}
+
+impl Chain {
+    /// Catmull-Rom spline through the joints, `samples` points per segment
+    pub fn curve(&self, samples: usize) -> Vec<Vec2> {
+        catmull_rom(&self.joints, samples)
+    }
+
+    /// Ribbon along [`Chain::curve`], tapering linearly from `base_width` to `tip_width`
+    pub fn ribbon(&self, samples: usize, base_width: f32, tip_width: f32) -> Ribbon {
+        ribbon(&self.curve(samples), |t| {
+            base_width + (tip_width - base_width) * t
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! Foot placement on terrain: end effector on the surface, joints kept above it.
+//!
+//! Terrain uses the same screen-space convention as [`Chain::new`]: `y` grows downward,
+//! so "above ground" means a smaller `y` than the surface.
+
+use crate::{Chain, SolveResult, SolveRun, Vec2};
+
+/// Solve/lift rounds used by [`Chain::solve_foot`] to push joints out of the ground
+const GROUND_PASSES: usize = 4;
+
+/// Ground surface as a function of `x`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Terrain {
+    /// Surface heights sampled every `spacing` units starting at `start_x`
+    Heightfield {
+        start_x: f32,
+        spacing: f32,
+        heights: Vec<f32>,
+    },
+    /// Surface through these points; where segments overlap the topmost one counts
+    Polyline(Vec<Vec2>),
+}
+
+impl Terrain {
+    /// Surface `y` at `x`, or `None` outside the terrain
+    pub fn height_at(&self, x: f32) -> Option<f32> {
+        match self {
+            Self::Heightfield {
+                start_x,
+                spacing,
+                heights,
+            } => {
+                // A non-positive spacing has no extent; NaN `u` falls outside the range too
+                if heights.is_empty() || spacing.is_nan() || *spacing <= 0.0 {
+                    return None;
+                }
+                let u = (x - start_x) / spacing;
+                if !(0.0..=(heights.len() - 1) as f32).contains(&u) {
+                    return None;
+                }
+                let i = (u as usize).min(heights.len().saturating_sub(2));
+                let next = heights.get(i + 1).copied().unwrap_or(heights[i]);
+                Some(heights[i] + (next - heights[i]) * (u - i as f32))
+            }
+            Self::Polyline(points) => points
+                .windows(2)
+                .filter_map(|w| {
+                    let (a, b) = if w[0].x <= w[1].x {
+                        (w[0], w[1])
+                    } else {
+                        (w[1], w[0])
+                    };
+                    if x < a.x || x > b.x {
+                        return None;
+                    }
+                    let t = if b.x > a.x {
+                        (x - a.x) / (b.x - a.x)
+                    } else {
+                        0.0
+                    };
+                    Some(a.y + (b.y - a.y) * t)
+                })
+                .reduce(f32::min),
+        }
+    }
+
+    /// Whether `p` is below the surface
+    #[inline]
+    pub fn is_below(&self, p: Vec2) -> bool {
+        self.height_at(p.x).is_some_and(|y| p.y > y)
+    }
+}
+
+impl Chain {
+    /// Plant the end effector on the terrain surface straight below (or above) `desired`,
+    /// then alternately lift buried joints onto the surface and re-solve so the rest of
+    /// the chain stays above ground as far as the segment lengths allow
+    pub fn solve_foot(&mut self, desired: impl Into<Vec2>, terrain: &Terrain) -> SolveResult {
+        let desired = desired.into();
+        let foot = terrain
+            .height_at(desired.x)
+            .map_or(desired, |y| Vec2::new(desired.x, y));
+
+        let mut result = self.solve(foot);
+        for _ in 0..GROUND_PASSES {
+            if !self.lift_joints(terrain) {
+                break;
+            }
+            let mut run = SolveRun::new(self.time_budget);
+            self.solve_pinned(&mut run, foot, true);
+            result = run.finish(self, foot);
+        }
+        self.reset_warm_start();
+        result
+    }
+
+    /// Move interior joints below the surface up onto it; returns whether any moved
+    fn lift_joints(&mut self, terrain: &Terrain) -> bool {
+        let interior = self.joints.len().saturating_sub(2);
+        let mut lifted = false;
+        for joint in self.joints.iter_mut().skip(1).take(interior) {
+            if let Some(y) = terrain.height_at(joint.x)
+                && joint.y > y
+            {
+                joint.y = y;
+                lifted = true;
+            }
+        }
+        lifted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Terrain;
+
+    #[test]
+    fn degenerate_heightfield_has_no_surface() {
+        for spacing in [0.0, -1.0, f32::NAN] {
+            let terrain = Terrain::Heightfield {
+                start_x: 0.0,
+                spacing,
+                heights: vec![1.0, 2.0],
+            };
+            assert_eq!(terrain.height_at(0.0), None);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Aiming at moving targets by solving for an intercept point.
+
+use crate::{Chain, SolveResult, Vec2};
+
+/// Earliest time `t >= 0` at which something leaving `from` at `speed` can meet a target
+/// moving from `position` with constant `velocity`, or `None` if it can never catch up
+pub fn intercept_time(from: Vec2, position: Vec2, velocity: Vec2, speed: f32) -> Option<f32> {
+    if speed.is_infinite() {
+        return Some(0.0);
+    }
+    // |d + v t| = s t  =>  (v·v - s²) t² + 2 (d·v) t + d·d = 0
+    let d = position - from;
+    let a = velocity.length_squared() - speed * speed;
+    let b = 2.0 * d.dot(velocity);
+    let c = d.length_squared();
+
+    if c == 0.0 {
+        return Some(0.0);
+    }
+    if a.abs() < f32::EPSILON {
+        // Equal speeds: linear equation
+        let t = -c / b;
+        return (t >= 0.0).then_some(t);
+    }
+
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let root = disc.sqrt();
+    let (t0, t1) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+    let (lo, hi) = (t0.min(t1), t0.max(t1));
+    if lo >= 0.0 {
+        Some(lo)
+    } else if hi >= 0.0 {
+        Some(hi)
+    } else {
+        None
+    }
+}
+
+impl Chain {
+    /// Where the end effector, moving at [`max_speed`](Self::max_speed), can meet a
+    /// target at `position` moving with `velocity`
+    pub fn intercept_point(&self, position: impl Into<Vec2>, velocity: Vec2) -> Option<Vec2> {
+        let position = position.into();
+        let end = *self.joints.last().unwrap();
+        let speed = self.max_speed.unwrap_or(f32::INFINITY);
+        intercept_time(end, position, velocity, speed).map(|t| position + velocity * t)
+    }
+
+    /// Solve toward the intercept point of a moving target, or its current position when
+    /// it cannot be caught
+    pub fn solve_intercept(&mut self, position: impl Into<Vec2>, velocity: Vec2) -> SolveResult {
+        let position = position.into();
+        let aim = self.intercept_point(position, velocity).unwrap_or(position);
+        self.solve(aim)
+    }
+}
@@ -0,0 +1,86 @@
+//! Conversions between `Vec2` and external math libraries (behind features).
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use crate::Vec2;
+
+    impl From<glam::Vec2> for Vec2 {
+        #[inline]
+        fn from(v: glam::Vec2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vec2> for glam::Vec2 {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            glam::Vec2::new(v.x, v.y)
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl {
+    use crate::Vec2;
+
+    impl From<nalgebra::Vector2<f32>> for Vec2 {
+        #[inline]
+        fn from(v: nalgebra::Vector2<f32>) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vec2> for nalgebra::Vector2<f32> {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            nalgebra::Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<nalgebra::Point2<f32>> for Vec2 {
+        #[inline]
+        fn from(p: nalgebra::Point2<f32>) -> Self {
+            Self::new(p.x, p.y)
+        }
+    }
+
+    impl From<Vec2> for nalgebra::Point2<f32> {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            nalgebra::Point2::new(v.x, v.y)
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use crate::Vec2;
+
+    impl From<mint::Vector2<f32>> for Vec2 {
+        #[inline]
+        fn from(v: mint::Vector2<f32>) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vec2> for mint::Vector2<f32> {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            mint::Vector2 { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<mint::Point2<f32>> for Vec2 {
+        #[inline]
+        fn from(p: mint::Point2<f32>) -> Self {
+            Self::new(p.x, p.y)
+        }
+    }
+
+    impl From<Vec2> for mint::Point2<f32> {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            mint::Point2 { x: v.x, y: v.y }
+        }
+    }
+}
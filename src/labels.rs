@@ -0,0 +1,84 @@
+//! Optional names and metadata for joints and segments.
+
+use std::collections::BTreeMap;
+
+use crate::{Chain, Vec2};
+
+/// Name and free-form key/value metadata attached to a joint or segment
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Label {
+    pub name: Option<String>,
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl Label {
+    #[inline]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.as_deref() == Some(name)
+    }
+}
+
+impl Chain {
+    /// Name a joint (index into `joints`), e.g. `"elbow"`
+    pub fn set_joint_name(&mut self, joint: usize, name: impl Into<String>) {
+        self.joint_labels[joint].name = Some(name.into());
+    }
+
+    /// Name several joints at once, base first
+    pub fn set_joint_names<S: Into<String>>(&mut self, names: impl IntoIterator<Item = S>) {
+        for (label, name) in self.joint_labels.iter_mut().zip(names) {
+            label.name = Some(name.into());
+        }
+    }
+
+    #[inline]
+    pub fn joint_name(&self, joint: usize) -> Option<&str> {
+        self.joint_labels[joint].name.as_deref()
+    }
+
+    /// Index of the first joint with this name
+    pub fn joint_index(&self, name: &str) -> Option<usize> {
+        self.joint_labels.iter().position(|l| l.is_named(name))
+    }
+
+    /// Position of the named joint
+    pub fn joint_by_name(&self, name: &str) -> Option<Vec2> {
+        self.joint_index(name).map(|i| self.joints[i])
+    }
+
+    #[inline]
+    pub fn joint_label(&self, joint: usize) -> &Label {
+        &self.joint_labels[joint]
+    }
+
+    #[inline]
+    pub fn joint_label_mut(&mut self, joint: usize) -> &mut Label {
+        &mut self.joint_labels[joint]
+    }
+
+    /// Name a segment (index into `lengths`), e.g. `"forearm"`
+    pub fn set_segment_name(&mut self, segment: usize, name: impl Into<String>) {
+        self.segment_labels[segment].name = Some(name.into());
+    }
+
+    #[inline]
+    pub fn segment_name(&self, segment: usize) -> Option<&str> {
+        self.segment_labels[segment].name.as_deref()
+    }
+
+    /// Index of the first segment with this name
+    pub fn segment_index(&self, name: &str) -> Option<usize> {
+        self.segment_labels.iter().position(|l| l.is_named(name))
+    }
+
+    #[inline]
+    pub fn segment_label(&self, segment: usize) -> &Label {
+        &self.segment_labels[segment]
+    }
+
+    #[inline]
+    pub fn segment_label_mut(&mut self, segment: usize) -> &mut Label {
+        &mut self.segment_labels[segment]
+    }
+}
@@ -1,115 +1,64 @@
 //! FABRIK (Forward And Backward Reaching Inverse Kinematics) implementation.
 
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
-
-/// 2D point/vector
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
-pub struct Vec2 {
-    pub x: f32,
-    pub y: f32,
-}
-
-impl Vec2 {
-    pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
-
-    #[inline]
-    pub const fn new(x: f32, y: f32) -> Self {
-        Self { x, y }
-    }
-
-    #[inline]
-    pub fn length_squared(self) -> f32 {
-        self.x * self.x + self.y * self.y
-    }
-
-    #[inline]
-    pub fn length(self) -> f32 {
-        self.length_squared().sqrt()
-    }
-
-    #[inline]
-    pub fn distance_squared(self, other: Self) -> f32 {
-        (self - other).length_squared()
-    }
-
-    #[inline]
-    pub fn distance(self, other: Self) -> f32 {
-        self.distance_squared(other).sqrt()
-    }
-
-    #[inline]
-    pub fn normalize(self) -> Self {
-        let len = self.length();
-        if len == 0.0 {
-            Self::ZERO
-        } else {
-            self * (1.0 / len)
-        }
-    }
-}
-
-impl From<(f32, f32)> for Vec2 {
-    #[inline]
-    fn from((x, y): (f32, f32)) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Add for Vec2 {
-    type Output = Self;
-    #[inline]
-    fn add(self, rhs: Self) -> Self {
-        Self::new(self.x + rhs.x, self.y + rhs.y)
-    }
-}
-
-impl AddAssign for Vec2 {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
-impl Sub for Vec2 {
-    type Output = Self;
-    #[inline]
-    fn sub(self, rhs: Self) -> Self {
-        Self::new(self.x - rhs.x, self.y - rhs.y)
-    }
-}
-
-impl SubAssign for Vec2 {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-    }
-}
-
-impl Mul<f32> for Vec2 {
-    type Output = Self;
-    #[inline]
-    fn mul(self, rhs: f32) -> Self {
-        Self::new(self.x * rhs, self.y * rhs)
-    }
-}
-
-impl MulAssign<f32> for Vec2 {
-    #[inline]
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-    }
-}
+mod blend;
+mod curve;
+mod ground;
+mod intercept;
+mod interop;
+mod labels;
+mod linkage;
+mod math;
+mod mobile;
+mod pose;
+mod skeleton;
+mod solutions;
+mod trajectory;
+mod verlet;
+mod walker;
+mod warm;
+mod workspace;
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub use blend::TargetBlend;
+pub use curve::{Ribbon, catmull_rom, ribbon};
+pub use ground::Terrain;
+pub use intercept::intercept_time;
+pub use labels::Label;
+pub use linkage::LoopClosure;
+pub use math::{Rot2, Transform2, Vec2, wrap_angle};
+pub use mobile::MobileBase;
+pub use pose::{Pose, PoseLibrary, RestPose};
+pub use skeleton::{ChainId, Skeleton};
+pub use solutions::Solution;
+pub use trajectory::{Interpolation, TrajectorySample};
+pub use verlet::Verlet;
+pub use walker::{Leg, Walker};
+pub use workspace::WorkspaceGrid;
 
 /// Configuration for a FABRIK chain
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChainConfig {
     pub segment_count: usize,
     pub segment_length: f32,
     pub tolerance: f32,
     pub max_iterations: usize,
+    /// Solver weight of the base joint, interpolated linearly toward `tip_weight`
+    pub base_weight: f32,
+    /// Solver weight of the end effector
+    pub tip_weight: f32,
+    /// Width of the band below full reach where the chain straightens gradually
+    pub soft_reach: f32,
+    /// Tolerance as a fraction of the chain's total length (`0.0` disables it)
+    pub relative_tolerance: f32,
+    /// Stop once no joint moves farther than this in one iteration (`0.0` disables it)
+    pub stall_threshold: f32,
+    /// Wall-clock limit for a single solve
+    pub time_budget: Option<Duration>,
+    /// Start each solve from a pose shifted by the target's motion since the last one
+    pub warm_start: bool,
 }
 
 impl Default for ChainConfig {
@@ -119,35 +68,106 @@ impl Default for ChainConfig {
             segment_length: 50.0,
             tolerance: 0.5,
             max_iterations: 10,
+            base_weight: 1.0,
+            tip_weight: 1.0,
+            soft_reach: 0.0,
+            relative_tolerance: 0.0,
+            stall_threshold: 0.0,
+            time_budget: None,
+            warm_start: false,
         }
     }
 }
 
 /// A kinematic chain of joints for FABRIK IK
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chain {
     pub joints: Vec<Vec2>,
     pub lengths: Vec<f32>,
     pub tolerance: f32,
     pub max_iterations: usize,
+    /// Displacement applied to interior joints once per solve, making slack segments sag.
+    /// Not scaled by time; for gravity as an acceleration use [`Verlet::gravity`].
+    pub sag_per_solve: Vec2,
+    /// Width of the band below full reach where the chain straightens gradually instead
+    /// of snapping straight once the target passes `total_length` (`0.0` disables it)
+    pub soft_reach: f32,
+    /// Tolerance as a fraction of `total_length`; the larger of both tolerances applies
+    pub relative_tolerance: f32,
+    /// Stop once no joint moves farther than this in one iteration (`0.0` disables it)
+    pub stall_threshold: f32,
+    /// Wall-clock limit for a single solve
+    pub time_budget: Option<Duration>,
+    /// Start each solve from a pose shifted by the target's motion since the last one
+    pub warm_start: bool,
+    /// When set, the origin walks toward targets beyond its comfortable reach within these limits
+    pub mobile_base: Option<MobileBase>,
+    /// When set, [`Chain::simulate`] gives joints inertia, gravity and damping
+    pub verlet: Option<Verlet>,
+    /// End-effector speed (units per second) assumed when intercepting moving targets;
+    /// `None` aims straight at the target's current position
+    pub max_speed: Option<f32>,
     origin: Vec2,
     total_length: f32,
+    /// Per-segment `(min, max)` length for telescopic segments, `None` when fixed
+    length_ranges: Vec<Option<(f32, f32)>>,
+    /// In rope mode, the length ranges to restore when it is turned off
+    rope: Option<Vec<Option<(f32, f32)>>>,
+    /// Per-joint fraction of each pass's rotation that is applied, in `(0, 1]`
+    weights: Vec<f32>,
+    /// Per-joint pin or secondary target for interior joints
+    goals: Vec<Option<JointGoal>>,
+    /// Eased weights for [`Chain::solve_weighted`]
+    blend: TargetBlend,
+    /// Pose the solver is biased toward and relaxes back to
+    rest: Option<RestPose>,
+    /// Optional names and metadata, one per joint and one per segment
+    joint_labels: Vec<Label>,
+    segment_labels: Vec<Label>,
+    /// Previous solve, for skipping repeats and warm starts
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_solve: Option<warm::LastSolve>,
+}
+
+/// Constraint on an interior joint, see [`Chain::set_joint_goal`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JointGoal {
+    /// Hold the joint at a position (as close as the segment lengths allow)
+    Pin(Vec2),
+    /// Pull the joint toward a position; `weight` in `[0, 1]` blends from the free pose to it
+    Target { position: Vec2, weight: f32 },
 }
 
 impl Chain {
     /// Create a new chain from config, extending upward from origin
-    pub fn new(origin: Vec2, config: &ChainConfig) -> Self {
+    pub fn new(origin: impl Into<Vec2>, config: &ChainConfig) -> Self {
         let lengths = vec![config.segment_length; config.segment_count];
-        Self::with_lengths(origin, lengths, config.tolerance, config.max_iterations)
+        let mut chain =
+            Self::with_lengths(origin, lengths, config.tolerance, config.max_iterations);
+
+        let last = chain.joints.len() - 1;
+        for (i, weight) in chain.weights.iter_mut().enumerate() {
+            let t = i as f32 / last.max(1) as f32;
+            *weight = lerp_weight(config.base_weight, config.tip_weight, t);
+        }
+        chain.soft_reach = config.soft_reach;
+        chain.relative_tolerance = config.relative_tolerance;
+        chain.stall_threshold = config.stall_threshold;
+        chain.time_budget = config.time_budget;
+        chain.warm_start = config.warm_start;
+        chain
     }
 
     /// Create a chain with variable segment lengths
     pub fn with_lengths(
-        origin: Vec2,
+        origin: impl Into<Vec2>,
         lengths: Vec<f32>,
         tolerance: f32,
         max_iterations: usize,
     ) -> Self {
+        let origin = origin.into();
         let total_length = lengths.iter().sum();
         let mut joints = Vec::with_capacity(lengths.len() + 1);
 
@@ -159,12 +179,30 @@ impl Chain {
         }
 
         Self {
+            length_ranges: vec![None; lengths.len()],
+            segment_labels: vec![Label::default(); lengths.len()],
+            joint_labels: vec![Label::default(); joints.len()],
+            weights: vec![1.0; joints.len()],
+            goals: vec![None; joints.len()],
             joints,
             lengths,
             tolerance,
             max_iterations,
+            sag_per_solve: Vec2::ZERO,
+            soft_reach: 0.0,
+            relative_tolerance: 0.0,
+            stall_threshold: 0.0,
+            time_budget: None,
+            warm_start: false,
+            mobile_base: None,
+            verlet: None,
+            max_speed: None,
             origin,
             total_length,
+            rope: None,
+            blend: TargetBlend::new(0.25),
+            rest: None,
+            last_solve: None,
         }
     }
 
@@ -174,18 +212,132 @@ impl Chain {
     }
 
     /// Update origin position
-    pub fn set_origin(&mut self, origin: Vec2) {
+    pub fn set_origin(&mut self, origin: impl Into<Vec2>) {
+        let origin = origin.into();
         self.origin = origin;
         self.joints[0] = origin;
     }
 
+    /// Move the origin and every joint by `offset`, keeping the pose. Under
+    /// [`verlet`](Self::verlet) the jump counts as motion; use [`set_origin`](Self::set_origin)
+    /// to drag a simulated chain by its base instead.
+    pub fn translate(&mut self, offset: impl Into<Vec2>) {
+        let offset = offset.into();
+        for joint in &mut self.joints {
+            *joint += offset;
+        }
+        self.origin += offset;
+    }
+
     /// Get origin position
     #[inline]
     pub fn origin(&self) -> Vec2 {
         self.origin
     }
 
-    /// Total reach of the chain (cached)
+    /// Make a segment telescopic: the solver may extend or retract it within `[min, max]`
+    pub fn set_length_range(&mut self, segment: usize, min: f32, max: f32) {
+        let (min, max) = (min.min(max), min.max(max));
+        self.length_ranges[segment] = Some((min, max));
+        self.lengths[segment] = self.lengths[segment].clamp(min, max);
+        self.update_total_length();
+    }
+
+    /// Make a telescopic segment fixed again at its current length
+    pub fn clear_length_range(&mut self, segment: usize) {
+        self.length_ranges[segment] = None;
+        self.update_total_length();
+    }
+
+    /// Allowed `(min, max)` length of a segment (equal for fixed segments)
+    #[inline]
+    pub fn length_range(&self, segment: usize) -> (f32, f32) {
+        self.length_ranges[segment].unwrap_or((self.lengths[segment], self.lengths[segment]))
+    }
+
+    /// Rope mode: every segment length becomes an upper bound so the chain can go slack.
+    /// Turning it off restores the ranges from before: fixed segments go back to their
+    /// maximum length, telescopic ones to their range. Ranges set in rope mode are dropped.
+    pub fn set_rope(&mut self, rope: bool) {
+        if rope == self.rope.is_some() {
+            return;
+        }
+        if rope {
+            self.rope = Some(self.length_ranges.clone());
+            for i in 0..self.lengths.len() {
+                let max = self.length_range(i).1;
+                self.length_ranges[i] = Some((0.0, max));
+            }
+        } else if let Some(saved) = self.rope.take() {
+            for (i, range) in saved.into_iter().enumerate() {
+                let max = self.length_range(i).1;
+                self.lengths[i] = match range {
+                    Some((min, max)) => self.lengths[i].clamp(min, max),
+                    None => max,
+                };
+                self.length_ranges[i] = range;
+            }
+        }
+        self.update_total_length();
+    }
+
+    /// Whether the chain is in rope mode
+    #[inline]
+    pub fn is_rope(&self) -> bool {
+        self.rope.is_some()
+    }
+
+    /// Set how much a joint moves per solver pass: `1.0` is plain FABRIK, lower values
+    /// keep the joint's segment closer to its previous direction (needs more iterations)
+    pub fn set_joint_weight(&mut self, joint: usize, weight: f32) {
+        self.weights[joint] = weight.clamp(MIN_WEIGHT, 1.0);
+        self.last_solve = None;
+    }
+
+    /// Set all joint weights at once, base first
+    pub fn set_joint_weights(&mut self, weights: &[f32]) {
+        for (joint, &weight) in weights.iter().enumerate().take(self.weights.len()) {
+            self.set_joint_weight(joint, weight);
+        }
+    }
+
+    #[inline]
+    pub fn joint_weight(&self, joint: usize) -> f32 {
+        self.weights[joint]
+    }
+
+    /// Constrain an interior joint; goals on the base or end effector are ignored
+    pub fn set_joint_goal(&mut self, joint: usize, goal: Option<JointGoal>) {
+        self.goals[joint] = goal;
+        self.last_solve = None;
+    }
+
+    /// Pin an interior joint to a position
+    pub fn pin_joint(&mut self, joint: usize, position: impl Into<Vec2>) {
+        self.set_joint_goal(joint, Some(JointGoal::Pin(position.into())));
+    }
+
+    /// Give an interior joint its own weighted target
+    pub fn set_joint_target(&mut self, joint: usize, position: impl Into<Vec2>, weight: f32) {
+        let goal = JointGoal::Target {
+            position: position.into(),
+            weight: weight.clamp(0.0, 1.0),
+        };
+        self.set_joint_goal(joint, Some(goal));
+    }
+
+    /// Remove any pin or target from a joint
+    pub fn clear_joint_goal(&mut self, joint: usize) {
+        self.set_joint_goal(joint, None);
+    }
+
+    /// Current pin or target of a joint
+    #[inline]
+    pub fn joint_goal(&self, joint: usize) -> Option<JointGoal> {
+        self.goals[joint]
+    }
+
+    /// Total reach of the chain, with telescopic segments fully extended (cached)
     #[inline]
     pub fn total_length(&self) -> f32 {
         self.total_length
@@ -203,57 +355,515 @@ impl Chain {
         self.lengths.len()
     }
 
-    /// Solve IK toward target using FABRIK
-    pub fn solve(&mut self, target: Vec2) {
+    fn update_total_length(&mut self) {
+        self.last_solve = None;
+        self.total_length = (0..self.lengths.len())
+            .map(|i| self.length_range(i).1)
+            .sum();
+    }
+
+    /// Length for segment `i` given the current offset between its joints
+    #[inline]
+    fn fit_length(&self, i: usize, delta: Vec2) -> f32 {
+        match self.length_ranges[i] {
+            Some((min, max)) => delta.length().clamp(min, max),
+            None => self.lengths[i],
+        }
+    }
+
+    /// Maximum length spanned by segments `lo..hi`
+    #[inline]
+    fn span_length(&self, lo: usize, hi: usize) -> f32 {
+        (lo..hi).map(|i| self.length_range(i).1).sum()
+    }
+
+    /// Whether some segment's joints are not `lengths[i]` apart, e.g. after `joints` or
+    /// `lengths` were edited directly; FABRIK then needs at least one pass to repair it
+    fn pose_broken(&self) -> bool {
+        let tolerance = self.effective_tolerance();
+        self.joints
+            .windows(2)
+            .zip(&self.lengths)
+            .any(|(w, &len)| (w[0].distance(w[1]) - len).abs() > tolerance)
+    }
+
+    /// Tolerance actually used by the solver: the larger of `tolerance` and
+    /// `relative_tolerance * total_length`
+    #[inline]
+    pub fn effective_tolerance(&self) -> f32 {
+        self.tolerance
+            .max(self.relative_tolerance * self.total_length)
+    }
+
+    /// Solve IK toward target using FABRIK.
+    ///
+    /// Repeating a settled solve with the same target, pose and settings returns the previous
+    /// result without iterating.
+    pub fn solve(&mut self, target: impl Into<Vec2>) -> SolveResult {
+        let target = target.into();
+        if let Some(result) = self.cached_result(target) {
+            return result;
+        }
+        self.follow_with_base(target);
+        let predicted = self.warm_start && self.predict(target);
+
+        let goal = self.soften_target(target);
+        let mut run = SolveRun::new(self.time_budget);
+        self.apply_rest_bias();
+        let sag = self.apply_sag();
+        let force = sag || predicted || self.pose_broken();
+        self.solve_pinned(&mut run, goal, force);
+
+        // Soft reach never stretches, so a target beyond full reach is reported here
+        let beyond = self.joints[0].distance(target) > self.total_length;
+        if goal != target && beyond {
+            run.record(SolveStatus::Unreachable);
+        }
+        let result = run.finish(self, goal);
+        self.record_solve(target, result);
+        result
+    }
+
+    /// Pull targets inside the soft-reach band toward the base so the reachable distance
+    /// approaches `total_length` asymptotically, never switching to the stretch branch
+    fn soften_target(&self, target: Vec2) -> Vec2 {
+        let soft = self.soft_reach.min(self.total_length);
+        if soft <= 0.0 {
+            return target;
+        }
         let base = self.joints[0];
-        let dist_sq = base.distance_squared(target);
-        let total_len = self.total_length;
-
-        // If target is unreachable, stretch toward it
-        if dist_sq >= total_len * total_len {
-            let dir = (target - base).normalize();
-            let mut pos = base;
-            for (i, &len) in self.lengths.iter().enumerate() {
-                pos += dir * len;
-                self.joints[i + 1] = pos;
+        let offset = target - base;
+        let dist = offset.length();
+        let hard = self.total_length - soft;
+        if dist <= hard {
+            return target;
+        }
+        let eased = hard + soft * (1.0 - (-(dist - hard) / soft).exp());
+        base + offset * (eased / dist)
+    }
+
+    /// Solve toward several weighted targets blended into one goal.
+    ///
+    /// Targets are matched by slice index across calls and their weights ease toward the
+    /// given values over [`blend_time`](Self::blend_time) seconds, advanced by `dt`.
+    /// Returns the blended goal and the solve result, or `None` (without solving) while
+    /// all weights are zero.
+    pub fn solve_weighted(
+        &mut self,
+        targets: &[(Vec2, f32)],
+        dt: f32,
+    ) -> Option<(Vec2, SolveResult)> {
+        let goal = self.blend.update(targets, dt)?;
+        Some((goal, self.solve(goal)))
+    }
+
+    /// Seconds for a weight in [`solve_weighted`](Self::solve_weighted) to fully change
+    #[inline]
+    pub fn blend_time(&self) -> f32 {
+        self.blend.blend_time
+    }
+
+    pub fn set_blend_time(&mut self, seconds: f32) {
+        self.blend.blend_time = seconds.max(0.0);
+    }
+
+    /// Solve with both ends fixed: the base at the origin and the last joint at `end`.
+    ///
+    /// `via` optionally pulls an interior joint `(index, point)` toward a point, as close
+    /// as the two sub-chains around it allow. If the anchors are farther apart than
+    /// [`total_length`](Self::total_length) the chain is left stretched toward `end`.
+    pub fn solve_anchored(
+        &mut self,
+        end: impl Into<Vec2>,
+        via: Option<(usize, Vec2)>,
+    ) -> Result<SolveResult, AnchorsTooFar> {
+        let end = end.into();
+        let last = self.lengths.len();
+        let base = self.joints[0];
+        self.last_solve = None;
+        let distance = base.distance(end);
+
+        if distance > self.total_length {
+            self.stretch(0, last, end);
+            return Err(AnchorsTooFar {
+                distance,
+                total_length: self.total_length,
+            });
+        }
+
+        let mut run = SolveRun::new(self.time_budget);
+        self.apply_rest_bias();
+        let sag = self.apply_sag();
+        match via {
+            Some((k, point)) if k > 0 && k < last => {
+                let (near, far) = (self.span_length(0, k), self.span_length(k, last));
+                let point = closest_in_disks(point, base, near, end, far);
+                self.reach(&mut run, 0, k, point, sag);
+                self.reach(&mut run, k, last, end, true);
+            }
+            _ => self.solve_pinned(&mut run, end, sag),
+        }
+        Ok(run.finish(self, end))
+    }
+
+    /// Let interior joints fall by `sag_per_solve`; returns whether anything moved
+    fn apply_sag(&mut self) -> bool {
+        if self.sag_per_solve == Vec2::ZERO {
+            return false;
+        }
+        let interior = self.joints.len().saturating_sub(2);
+        for joint in self.joints.iter_mut().skip(1).take(interior) {
+            *joint += self.sag_per_solve;
+        }
+        true
+    }
+
+    /// Split the chain at pinned joints and solve each piece toward the next pin,
+    /// the last one toward `target`
+    fn solve_pinned(&mut self, run: &mut SolveRun, target: Vec2, sag: bool) {
+        let last = self.lengths.len();
+        let mut lo = 0;
+        for k in 1..last {
+            if let Some(JointGoal::Pin(pin)) = self.goals[k] {
+                self.solve_span(run, lo, k, pin, sag || lo > 0);
+                lo = k;
+            }
+        }
+        self.solve_span(run, lo, last, target, sag || lo > 0);
+    }
+
+    /// Solve joints `lo..=hi` toward `goal`, splitting again at every weighted joint target:
+    /// the free pose locates the joint, which is then pulled toward its target as far as
+    /// both sides of the split can still reach
+    fn solve_span(&mut self, run: &mut SolveRun, lo: usize, hi: usize, goal: Vec2, force: bool) {
+        let (mut lo, mut force) = (lo, force);
+        for k in lo + 1..hi {
+            let Some(JointGoal::Target { position, weight }) = self.goals[k] else {
+                continue;
+            };
+            self.reach(run, lo, hi, goal, force);
+            let wanted = self.joints[k].lerp(position, weight);
+            let (near, far) = (self.span_length(lo, k), self.span_length(k, hi));
+            let point = closest_in_disks(wanted, self.joints[lo], near, goal, far);
+            self.reach(run, lo, k, point, true);
+            lo = k;
+            force = true;
+        }
+        self.reach(run, lo, hi, goal, force);
+    }
+
+    /// Solve the sub-chain of joints `lo..=hi` toward `target`, keeping `joints[lo]` fixed.
+    /// `force` runs at least one pass even when `joints[hi]` already sits on the target,
+    /// needed after sagging or when `joints[lo]` was moved by an earlier split.
+    /// The outcome is recorded in `run`.
+    fn reach(&mut self, run: &mut SolveRun, lo: usize, hi: usize, target: Vec2, force: bool) {
+        let base = self.joints[lo];
+        let span = self.span_length(lo, hi);
+        let tolerance = self.effective_tolerance();
+        let tolerance_sq = tolerance * tolerance;
+
+        // If target is unreachable, stretch toward it (telescopic segments fully extended).
+        // Split points clamped onto the edge of reach are met exactly and still count.
+        if base.distance_squared(target) >= span * span {
+            self.stretch(lo, hi, target);
+            if self.joints[hi].distance_squared(target) >= tolerance_sq {
+                run.record(SolveStatus::Unreachable);
             }
             return;
         }
 
-        let tolerance_sq = self.tolerance * self.tolerance;
+        let stall_sq = self.stall_threshold * self.stall_threshold;
+        let mut before = Vec::new();
+        let mut status = SolveStatus::MaxIterations;
 
         // FABRIK iterations
-        for _ in 0..self.max_iterations {
-            let end_effector = *self.joints.last().unwrap();
-            if end_effector.distance_squared(target) < tolerance_sq {
+        for iteration in 0..self.max_iterations {
+            let on_target = self.joints[hi].distance_squared(target) < tolerance_sq;
+            if on_target && !(force && iteration == 0) {
+                status = SolveStatus::Converged;
                 break;
             }
+            if run.out_of_time() {
+                status = SolveStatus::TimedOut;
+                break;
+            }
+
+            if stall_sq > 0.0 {
+                before.clear();
+                before.extend_from_slice(&self.joints[lo..=hi]);
+            }
+
+            self.forward_reach(lo, hi, target);
+            self.backward_reach(lo, hi, base);
+            run.iterations += 1;
+
+            if stall_sq > 0.0 {
+                let moved_sq = before
+                    .iter()
+                    .zip(&self.joints[lo..=hi])
+                    .map(|(&a, &b)| a.distance_squared(b))
+                    .fold(0.0, f32::max);
+                if moved_sq < stall_sq {
+                    status = SolveStatus::Stalled;
+                    break;
+                }
+            }
+        }
+
+        // The last pass may have landed on target without a check afterwards
+        if status != SolveStatus::TimedOut
+            && self.joints[hi].distance_squared(target) < tolerance_sq
+        {
+            status = SolveStatus::Converged;
+        }
+        run.record(status);
+    }
 
-            self.forward_reach(target);
-            self.backward_reach(base);
+    /// Lay joints `lo..=hi` in a straight line from `joints[lo]` toward `target`
+    fn stretch(&mut self, lo: usize, hi: usize, target: Vec2) {
+        let dir = (target - self.joints[lo]).normalize();
+        let mut pos = self.joints[lo];
+        for i in lo..hi {
+            self.lengths[i] = self.length_range(i).1;
+            pos += dir * self.lengths[i];
+            self.joints[i + 1] = pos;
         }
     }
 
-    /// Forward pass: move end effector to target, propagate to base
+    /// Direction for the segment moved by `joint`, rotating only `weights[joint]` of the
+    /// way from its direction before the pass (`old`) to the FABRIK one (`new`)
     #[inline]
-    fn forward_reach(&mut self, target: Vec2) {
-        let n = self.joints.len();
-        self.joints[n - 1] = target;
+    fn weighted_dir(&self, joint: usize, old: Vec2, new: Vec2) -> Vec2 {
+        let new = new.normalize();
+        let weight = self.weights[joint];
+        if weight >= 1.0 {
+            return new;
+        }
+        let dir = old.normalize().lerp(new, weight).normalize();
+        if dir == Vec2::ZERO { new } else { dir }
+    }
 
-        for i in (0..n - 1).rev() {
-            let dir = (self.joints[i] - self.joints[i + 1]).normalize();
+    /// Forward pass: move `joints[hi]` to target, propagate down to `joints[lo]`
+    #[inline]
+    fn forward_reach(&mut self, lo: usize, hi: usize, target: Vec2) {
+        let mut old_next = self.joints[hi];
+        self.joints[hi] = target;
+
+        for i in (lo..hi).rev() {
+            let delta = self.joints[i] - self.joints[i + 1];
+            self.lengths[i] = self.fit_length(i, delta);
+            let dir = self.weighted_dir(i, self.joints[i] - old_next, delta);
+            old_next = self.joints[i];
             self.joints[i] = self.joints[i + 1] + dir * self.lengths[i];
         }
     }
 
-    /// Backward pass: anchor base, propagate to end
+    /// Backward pass: anchor `joints[lo]` at base, propagate up to `joints[hi]`
     #[inline]
-    fn backward_reach(&mut self, base: Vec2) {
-        self.joints[0] = base;
-
-        for i in 0..self.lengths.len() {
-            let dir = (self.joints[i + 1] - self.joints[i]).normalize();
+    fn backward_reach(&mut self, lo: usize, hi: usize, base: Vec2) {
+        let mut old_prev = self.joints[lo];
+        self.joints[lo] = base;
+
+        for i in lo..hi {
+            let delta = self.joints[i + 1] - self.joints[i];
+            self.lengths[i] = self.fit_length(i, delta);
+            let dir = self.weighted_dir(i + 1, self.joints[i + 1] - old_prev, delta);
+            old_prev = self.joints[i + 1];
             self.joints[i + 1] = self.joints[i] + dir * self.lengths[i];
         }
     }
 }
+
+/// Why a solve stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolveStatus {
+    /// End effector within tolerance of the target
+    Converged,
+    /// Target out of reach; the chain was stretched toward it
+    Unreachable,
+    /// Joints stopped moving more than `stall_threshold` per iteration
+    Stalled,
+    /// The `time_budget` ran out
+    TimedOut,
+    /// Ran `max_iterations` without meeting another criterion
+    MaxIterations,
+}
+
+impl SolveStatus {
+    /// How bad an outcome is, for combining the sub-chains of one solve
+    fn severity(self) -> u8 {
+        match self {
+            Self::Converged => 0,
+            Self::Stalled | Self::MaxIterations => 1,
+            Self::Unreachable | Self::TimedOut => 2,
+        }
+    }
+}
+
+/// Summary of a [`Chain::solve`] call
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveResult {
+    pub status: SolveStatus,
+    /// FABRIK iterations run, summed over all sub-chains
+    pub iterations: usize,
+    /// Distance from the end effector to the requested target, or to the eased goal the
+    /// chain was solved toward under `soft_reach`
+    pub error: f32,
+    /// Origin the chain was solved from; differs from before only in mobile-base mode
+    pub origin: Vec2,
+}
+
+impl SolveResult {
+    #[inline]
+    pub fn converged(&self) -> bool {
+        self.status == SolveStatus::Converged
+    }
+}
+
+/// Bookkeeping shared by every sub-chain solved within one call
+struct SolveRun {
+    deadline: Option<Instant>,
+    iterations: usize,
+    status: SolveStatus,
+}
+
+impl SolveRun {
+    fn new(budget: Option<Duration>) -> Self {
+        Self {
+            deadline: budget.map(|budget| Instant::now() + budget),
+            iterations: 0,
+            status: SolveStatus::Converged,
+        }
+    }
+
+    #[inline]
+    fn out_of_time(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Keep the worst outcome of all sub-chains solved so far
+    fn record(&mut self, status: SolveStatus) {
+        if status.severity() > self.status.severity() {
+            self.status = status;
+        }
+    }
+
+    fn finish(self, chain: &Chain, target: Vec2) -> SolveResult {
+        SolveResult {
+            status: self.status,
+            iterations: self.iterations,
+            error: chain.joints.last().unwrap().distance(target),
+            origin: chain.origin,
+        }
+    }
+}
+
+/// Lowest joint weight; a zero weight would freeze the joint and stall the solver
+const MIN_WEIGHT: f32 = 0.01;
+
+#[inline]
+fn lerp_weight(base: f32, tip: f32, t: f32) -> f32 {
+    (base + (tip - base) * t).clamp(MIN_WEIGHT, 1.0)
+}
+
+/// Failure of [`Chain::solve_anchored`]: the anchors cannot be bridged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnchorsTooFar {
+    pub distance: f32,
+    pub total_length: f32,
+}
+
+impl fmt::Display for AnchorsTooFar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "anchors are {:.2} apart but the chain only reaches {:.2}",
+            self.distance, self.total_length
+        )
+    }
+}
+
+impl std::error::Error for AnchorsTooFar {}
+
+/// Point closest to `p` inside both disks `(a, ra)` and `(b, rb)`, by alternating projection
+fn closest_in_disks(p: Vec2, a: Vec2, ra: f32, b: Vec2, rb: f32) -> Vec2 {
+    let mut p = p;
+    for _ in 0..16 {
+        p = a + (p - a).clamp_length(ra);
+        p = b + (p - b).clamp_length(rb);
+        if p.distance_squared(a) <= ra * ra {
+            break;
+        }
+    }
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(segments: usize) -> Chain {
+        Chain::with_lengths(Vec2::ZERO, vec![10.0; segments], 0.5, 20)
+    }
+
+    #[test]
+    fn worst_span_status_is_reported() {
+        let mut chain = chain(4);
+        chain.pin_joint(3, Vec2::new(500.0, 0.0));
+        let result = chain.solve(Vec2::new(20.0, 10.0));
+        assert_eq!(result.status, SolveStatus::Unreachable);
+    }
+
+    #[test]
+    fn clamped_joint_target_still_converges() {
+        let mut chain = chain(4);
+        chain.set_joint_target(2, Vec2::new(0.0, 20.0), 1.0);
+        let result = chain.solve(Vec2::new(20.0, 10.0));
+        assert_eq!(result.status, SolveStatus::Converged, "{result:?}");
+    }
+
+    #[test]
+    fn clamped_via_point_still_converges() {
+        let mut chain = chain(4);
+        let result = chain
+            .solve_anchored(Vec2::new(30.0, 0.0), Some((2, Vec2::new(15.0, 50.0))))
+            .unwrap();
+        assert_eq!(result.status, SolveStatus::Converged, "{result:?}");
+    }
+
+    #[test]
+    fn rope_mode_restores_telescopic_ranges() {
+        let mut chain = chain(3);
+        chain.set_length_range(1, 5.0, 20.0);
+        chain.set_rope(true);
+        assert_eq!(chain.length_range(0), (0.0, 10.0));
+        assert_eq!(chain.length_range(1), (0.0, 20.0));
+        chain.solve(Vec2::new(3.0, 3.0));
+
+        chain.set_rope(false);
+        assert_eq!(chain.length_range(0), (10.0, 10.0));
+        assert_eq!(chain.length_range(1), (5.0, 20.0));
+        assert_eq!(chain.length_range(2), (10.0, 10.0));
+        assert_eq!(chain.total_length(), 40.0);
+    }
+
+    #[test]
+    fn soft_reach_reports_status_against_its_goal() {
+        let mut chain = chain(4);
+        chain.soft_reach = 10.0;
+
+        let inside = chain.solve(Vec2::new(35.0, 0.0));
+        if inside.converged() {
+            assert!(inside.error <= chain.effective_tolerance(), "{inside:?}");
+        }
+
+        let beyond = chain.solve(Vec2::new(50.0, 0.0));
+        assert_eq!(beyond.status, SolveStatus::Unreachable);
+        let again = chain.solve(Vec2::new(50.0, 0.0));
+        assert_eq!(again.status, SolveStatus::Unreachable);
+        assert_eq!(again.iterations, 0);
+    }
+}
@@ -0,0 +1,80 @@
+//! Closed kinematic loops such as four-bar linkages.
+
+use crate::{Chain, SolveResult, SolveRun, SolveStatus, Vec2};
+
+/// What the end of a closed-loop chain must coincide with, see [`Chain::solve_loop`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LoopClosure {
+    /// A fixed point, e.g. the second ground pivot of a four-bar linkage
+    Point(Vec2),
+    /// An earlier joint of the same chain (index into `joints`), which moves with it
+    Joint(usize),
+}
+
+impl Chain {
+    /// Solve the chain as a closed loop: the base stays at the origin and the end effector
+    /// is brought onto `closure`.
+    ///
+    /// `driver` holds segment `(index, angle)` at a world angle (radians from +X), like the
+    /// crank of a mechanism; segments before it keep their pose and only the rest of the
+    /// chain moves to close the loop. A four-bar linkage is a three-segment chain (crank,
+    /// coupler, rocker) from one ground pivot with [`LoopClosure::Point`] at the other,
+    /// driven by segment `0`. Joint weights apply; pins and joint targets are not used.
+    pub fn solve_loop(
+        &mut self,
+        closure: LoopClosure,
+        driver: Option<(usize, f32)>,
+    ) -> SolveResult {
+        let last = self.lengths.len();
+        self.last_solve = None;
+
+        let mut lo = 0;
+        if let Some((segment, angle)) = driver.filter(|&(s, _)| s < last) {
+            self.joints[segment + 1] =
+                self.joints[segment] + Vec2::from_angle(angle) * self.lengths[segment];
+            lo = segment + 1;
+        }
+
+        let closing = |chain: &Self| match closure {
+            LoopClosure::Point(p) => p,
+            LoopClosure::Joint(j) => chain.joints[j.min(last)],
+        };
+        let mut run = SolveRun::new(self.time_budget);
+        let base = self.joints[lo];
+        let moving = matches!(closure, LoopClosure::Joint(j) if j >= lo);
+
+        // A fixed goal out of reach of the free part can only be stretched toward
+        let goal = closing(self);
+        if !moving && base.distance(goal) > self.span_length(lo, last) {
+            self.stretch(lo, last, goal);
+            run.status = SolveStatus::Unreachable;
+            return run.finish(self, goal);
+        }
+
+        // Always run a pass: moving the driver changes lengths the end cannot see
+        let tolerance = self.effective_tolerance();
+        run.status = SolveStatus::MaxIterations;
+        while run.iterations < self.max_iterations && lo < last {
+            if run.out_of_time() {
+                run.status = SolveStatus::TimedOut;
+                break;
+            }
+            let goal = closing(self);
+            self.forward_reach(lo, last, goal);
+            self.backward_reach(lo, last, base);
+            run.iterations += 1;
+            if self.joints[last].distance(closing(self)) <= tolerance {
+                run.status = SolveStatus::Converged;
+                break;
+            }
+        }
+
+        // Nothing left to move when the driver is the last segment
+        let goal = closing(self);
+        if lo == last && self.joints[last].distance(goal) <= tolerance {
+            run.status = SolveStatus::Converged;
+        }
+        run.finish(self, goal)
+    }
+}
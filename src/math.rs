@@ -0,0 +1,439 @@
+//! Minimal 2D math: vectors, rotations and rigid transforms.
+
+use std::f32::consts::{PI, TAU};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Wrap an angle in radians into `(-π, π]`
+#[inline]
+pub fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped == -PI { PI } else { wrapped }
+}
+
+/// 2D point/vector
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self { x: 0.0, y: 0.0 };
+    pub const ONE: Self = Self { x: 1.0, y: 1.0 };
+    pub const X: Self = Self { x: 1.0, y: 0.0 };
+    pub const Y: Self = Self { x: 0.0, y: 1.0 };
+
+    #[inline]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Unit vector pointing at `angle` radians from the +X axis
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> f32 {
+        (self - other).length_squared()
+    }
+
+    #[inline]
+    pub fn distance(self, other: Self) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let len = self.length();
+        if len == 0.0 {
+            Self::ZERO
+        } else {
+            self * (1.0 / len)
+        }
+    }
+
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Z component of the 3D cross product (positive when `other` is counter-clockwise)
+    #[inline]
+    pub fn cross(self, other: Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Vector rotated 90° counter-clockwise
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Angle from the +X axis in radians, in `(-π, π]`
+    #[inline]
+    pub fn angle(self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Signed angle rotating `self` onto `other`, in `(-π, π]`
+    #[inline]
+    pub fn angle_to(self, other: Self) -> f32 {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Rotate counter-clockwise by `angle` radians
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Self {
+        Rot2::from_angle(angle) * self
+    }
+
+    /// Linear interpolation, `t = 0` yields `self` and `t = 1` yields `other`
+    #[inline]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Scale down to `max` length if longer
+    #[inline]
+    pub fn clamp_length(self, max: f32) -> Self {
+        let len_sq = self.length_squared();
+        if len_sq > max * max {
+            self * (max / len_sq.sqrt())
+        } else {
+            self
+        }
+    }
+}
+
+impl From<(f32, f32)> for Vec2 {
+    #[inline]
+    fn from((x, y): (f32, f32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[f32; 2]> for Vec2 {
+    #[inline]
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Vec2> for (f32, f32) {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<Vec2> for [f32; 2] {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Mul<f32> for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Mul<Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        rhs * self
+    }
+}
+
+impl MulAssign<f32> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl Div<f32> for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl DivAssign<f32> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// 2D rotation stored as a unit complex number (cos, sin)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rot2 {
+    pub cos: f32,
+    pub sin: f32,
+}
+
+impl Default for Rot2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Rot2 {
+    pub const IDENTITY: Self = Self { cos: 1.0, sin: 0.0 };
+
+    /// Counter-clockwise rotation by `angle` radians
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { cos, sin }
+    }
+
+    /// Rotation taking direction `from` onto direction `to`
+    #[inline]
+    pub fn between(from: Vec2, to: Vec2) -> Self {
+        Self::from_angle(from.angle_to(to))
+    }
+
+    #[inline]
+    pub fn angle(self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    #[inline]
+    pub fn inverse(self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+}
+
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, v: Vec2) -> Vec2 {
+        Vec2::new(
+            self.cos * v.x - self.sin * v.y,
+            self.sin * v.x + self.cos * v.y,
+        )
+    }
+}
+
+impl Mul for Rot2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+/// Rigid 2D transform: rotation followed by translation
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2 {
+    pub translation: Vec2,
+    pub rotation: Rot2,
+}
+
+impl Transform2 {
+    pub const IDENTITY: Self = Self {
+        translation: Vec2::ZERO,
+        rotation: Rot2::IDENTITY,
+    };
+
+    #[inline]
+    pub fn new(translation: Vec2, angle: f32) -> Self {
+        Self {
+            translation,
+            rotation: Rot2::from_angle(angle),
+        }
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            translation,
+            rotation: Rot2::IDENTITY,
+        }
+    }
+
+    /// Map a point from local into parent space
+    #[inline]
+    pub fn transform_point(self, p: Vec2) -> Vec2 {
+        self.rotation * p + self.translation
+    }
+
+    /// Map a direction from local into parent space (ignores translation)
+    #[inline]
+    pub fn transform_vector(self, v: Vec2) -> Vec2 {
+        self.rotation * v
+    }
+
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            translation: -(rotation * self.translation),
+            rotation,
+        }
+    }
+}
+
+impl Mul for Transform2 {
+    type Output = Self;
+    /// Compose so that `(a * b).transform_point(p) == a.transform_point(b.transform_point(p))`
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    const EPS: f32 = 1e-5;
+
+    fn approx(a: Vec2, b: Vec2) -> bool {
+        a.distance(b) < EPS
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, -1.0);
+        assert_eq!(a.dot(b), 1.0);
+        assert_eq!(a.cross(b), -7.0);
+        assert_eq!(Vec2::X.cross(Vec2::Y), 1.0);
+    }
+
+    #[test]
+    fn perp_is_ccw_quarter_turn() {
+        assert!(approx(Vec2::X.perp(), Vec2::Y));
+        assert!(approx(Vec2::X.perp(), Vec2::X.rotate(FRAC_PI_2)));
+    }
+
+    #[test]
+    fn angles() {
+        assert!((Vec2::Y.angle() - FRAC_PI_2).abs() < EPS);
+        assert!((Vec2::X.angle_to(Vec2::Y) - FRAC_PI_2).abs() < EPS);
+        assert!((Vec2::Y.angle_to(Vec2::X) + FRAC_PI_2).abs() < EPS);
+        assert!((Vec2::X.angle_to(-Vec2::X).abs() - PI).abs() < EPS);
+        assert!(approx(Vec2::from_angle(0.3), Vec2::X.rotate(0.3)));
+    }
+
+    #[test]
+    fn lerp_and_ops() {
+        let a = Vec2::new(0.0, 10.0);
+        let b = Vec2::new(10.0, 0.0);
+        assert!(approx(a.lerp(b, 0.5), Vec2::new(5.0, 5.0)));
+        assert_eq!(-a, Vec2::new(0.0, -10.0));
+        assert_eq!(a / 2.0, Vec2::new(0.0, 5.0));
+        assert_eq!(2.0 * b, b * 2.0);
+        assert!(approx(
+            Vec2::new(30.0, 40.0).clamp_length(5.0),
+            Vec2::new(3.0, 4.0)
+        ));
+    }
+
+    #[test]
+    fn wrap_angle_range() {
+        assert!((wrap_angle(3.0 * PI) - PI).abs() < EPS);
+        assert!((wrap_angle(-PI) - PI).abs() < EPS);
+        assert!((wrap_angle(0.5 - TAU) - 0.5).abs() < EPS);
+    }
+
+    #[test]
+    fn rot2_compose_and_inverse() {
+        let r = Rot2::from_angle(0.4) * Rot2::from_angle(0.6);
+        assert!((r.angle() - 1.0).abs() < EPS);
+        let v = Vec2::new(2.0, -3.0);
+        assert!(approx(r.inverse() * (r * v), v));
+        let between = Rot2::between(Vec2::X, Vec2::new(1.0, 1.0));
+        assert!((between.angle() - PI / 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn transform2_roundtrip() {
+        let a = Transform2::new(Vec2::new(5.0, 1.0), 0.7);
+        let b = Transform2::new(Vec2::new(-2.0, 3.0), -1.2);
+        let p = Vec2::new(1.5, -0.5);
+
+        assert!(approx(
+            (a * b).transform_point(p),
+            a.transform_point(b.transform_point(p))
+        ));
+        assert!(approx(a.inverse().transform_point(a.transform_point(p)), p));
+        assert!(approx(a.transform_vector(Vec2::X), Vec2::from_angle(0.7)));
+    }
+}
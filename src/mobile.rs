@@ -0,0 +1,129 @@
+//! Mobile-base mode: moving the root when a target is out of reach.
+
+use std::f32::consts::PI;
+
+use crate::{Chain, Vec2};
+
+/// Sideways bend, per unit of segment length, given to a straight pose before solving
+const UNFOLD_BEND: f32 = 0.2;
+
+/// Limits on how the base may move in mobile-base mode, see [`Chain::mobile_base`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MobileBase {
+    /// Axis-aligned `(min, max)` corners the origin must stay within
+    pub region: Option<(Vec2, Vec2)>,
+    /// Farthest the origin may travel in one solve (a speed limit at a fixed frame rate);
+    /// `None` is unlimited
+    pub max_step: Option<f32>,
+    /// Fraction of `total_length` at which the base stops approaching, so the chain
+    /// reaches with some bend left instead of fully stretched
+    pub reach_fraction: f32,
+}
+
+impl Default for MobileBase {
+    fn default() -> Self {
+        Self {
+            region: None,
+            max_step: None,
+            reach_fraction: 0.9,
+        }
+    }
+}
+
+impl MobileBase {
+    /// Where the base goes so `target` ends up within the comfortable reach, subject to
+    /// the limits
+    pub(crate) fn step(&self, base: Vec2, target: Vec2, total_length: f32) -> Vec2 {
+        let offset = target - base;
+        let dist = offset.length();
+        let comfortable = total_length * self.reach_fraction.clamp(0.0, 1.0);
+        if dist <= comfortable {
+            return base;
+        }
+
+        let max_step = self.max_step.map_or(f32::INFINITY, |step| step.max(0.0));
+        let travel = (dist - comfortable).min(max_step);
+        let mut next = base + offset * (travel / dist);
+        if let Some((min, max)) = self.region {
+            next = Vec2::new(next.x.clamp(min.x, max.x), next.y.clamp(min.y, max.y));
+        }
+        next
+    }
+}
+
+impl Chain {
+    /// In mobile-base mode, translate the whole pose toward a target beyond the comfortable
+    /// reach; the new origin is reported in [`SolveResult::origin`](crate::SolveResult::origin)
+    pub(crate) fn follow_with_base(&mut self, target: Vec2) {
+        let Some(mobile) = self.mobile_base else {
+            return;
+        };
+        let base = self.joints[0];
+        let next = mobile.step(base, target, self.total_length);
+        if next == base {
+            return;
+        }
+        self.translate(next - base);
+        // Moving the base is not motion the simulated joints should inherit
+        if let Some(verlet) = self.verlet.as_mut() {
+            verlet.shift(next - base);
+        }
+        self.unfold(target);
+    }
+
+    /// A pose stretched toward the target stays on the base-target line, where FABRIK
+    /// can only slide it back and forth; bend it sideways so the solver can fold it
+    fn unfold(&mut self, target: Vec2) {
+        let base = self.joints[0];
+        let axis = (target - base).normalize();
+        let tolerance = self.effective_tolerance();
+        let straight = self
+            .joints
+            .iter()
+            .all(|&p| (p - base).cross(axis).abs() <= tolerance);
+        if axis == Vec2::ZERO || !straight {
+            return;
+        }
+
+        let last = self.joints.len() - 1;
+        let side = axis.perp();
+        for i in 1..last {
+            let bend = (PI * i as f32 / last as f32).sin() * UNFOLD_BEND;
+            self.joints[i] += side * (self.lengths[i - 1] * bend);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, ChainConfig, MobileBase, SolveStatus, Vec2};
+
+    #[test]
+    fn far_target_is_reached_on_a_straight_line() {
+        let mut chain = Chain::new(Vec2::ZERO, &ChainConfig::default());
+        chain.mobile_base = Some(MobileBase::default());
+        let target = Vec2::new(0.0, -1000.0);
+
+        let results: Vec<_> = (0..5).map(|_| chain.solve(target)).collect();
+        assert!(results.last().unwrap().converged(), "{results:?}");
+    }
+
+    #[test]
+    fn limited_steps_approach_to_comfortable_reach() {
+        let mut chain = Chain::with_lengths(Vec2::ZERO, vec![10.0; 3], 0.5, 10);
+        chain.mobile_base = Some(MobileBase {
+            max_step: Some(5.0),
+            ..MobileBase::default()
+        });
+        let target = Vec2::new(60.0, 0.0);
+
+        let mut result = chain.solve(target);
+        for _ in 0..20 {
+            result = chain.solve(target);
+        }
+        assert_eq!(result.status, SolveStatus::Converged);
+        let comfortable = chain.total_length() * 0.9;
+        assert!(chain.origin().distance(target) <= comfortable + 1e-3);
+    }
+}
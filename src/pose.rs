@@ -0,0 +1,271 @@
+//! Angle-space view of a chain pose, pose snapshots and blending, and the rest pose it
+//! springs back to.
+
+use std::collections::BTreeMap;
+
+use crate::math::wrap_angle;
+use crate::{Chain, Vec2};
+
+/// Preferred pose as local segment angles, with a per-segment pull toward it.
+///
+/// `angles[0]` is the world direction of the first segment (radians from +X), every
+/// following angle is relative to the previous segment. `stiffness` in `[0, 1]` is the
+/// fraction of the remaining angle error removed on every solve or relax step.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RestPose {
+    pub angles: Vec<f32>,
+    pub stiffness: Vec<f32>,
+}
+
+impl RestPose {
+    /// Rest pose from local angles with uniform stiffness
+    pub fn from_angles(angles: Vec<f32>, stiffness: f32) -> Self {
+        let stiffness = vec![stiffness.clamp(0.0, 1.0); angles.len()];
+        Self { angles, stiffness }
+    }
+
+    /// Rest pose from a snapshot with uniform stiffness
+    pub fn from_pose(pose: &Pose, stiffness: f32) -> Self {
+        Self::from_angles(pose.angles.clone(), stiffness)
+    }
+
+    /// Rest pose matching the given joint positions
+    pub fn from_joints(joints: &[Vec2], stiffness: f32) -> Self {
+        Self::from_angles(local_angles(joints), stiffness)
+    }
+}
+
+/// Snapshot of a chain pose as local segment angles (see [`RestPose`] for the convention).
+///
+/// Angles are independent of where the base is, so a pose can be applied to any chain
+/// with the same segment count, and blended with others for animation layering.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pose {
+    pub angles: Vec<f32>,
+}
+
+impl Pose {
+    pub fn from_angles(angles: Vec<f32>) -> Self {
+        Self { angles }
+    }
+
+    pub fn from_joints(joints: &[Vec2]) -> Self {
+        Self::from_angles(local_angles(joints))
+    }
+
+    /// Weighted blend of several poses, angle by angle as a circular mean so blending
+    /// across the ±π seam takes the short way. Weights need not sum to one; `None` when
+    /// they sum to zero or less. Poses with fewer angles only contribute to those they have.
+    pub fn blend<'a>(poses: impl IntoIterator<Item = (&'a Pose, f32)>) -> Option<Pose> {
+        let mut sums: Vec<(Vec2, f32)> = Vec::new();
+        for (pose, weight) in poses {
+            if sums.len() < pose.angles.len() {
+                sums.resize(pose.angles.len(), (Vec2::ZERO, 0.0));
+            }
+            for (sum, &angle) in sums.iter_mut().zip(&pose.angles) {
+                sum.0 += Vec2::from_angle(angle) * weight;
+                sum.1 += weight;
+            }
+        }
+        if sums.first().is_none_or(|&(_, total)| total <= 0.0) {
+            return None;
+        }
+        let angles = sums.iter().map(|(dir, _)| dir.angle()).collect();
+        Some(Self::from_angles(angles))
+    }
+
+    /// Blend from `self` (`t = 0`) to `other` (`t = 1`), each angle the short way round
+    pub fn lerp(&self, other: &Pose, t: f32) -> Pose {
+        let angles = self
+            .angles
+            .iter()
+            .zip(&other.angles)
+            .map(|(&a, &b)| wrap_angle(a + wrap_angle(b - a) * t))
+            .collect();
+        Self::from_angles(angles)
+    }
+}
+
+/// Named poses, e.g. `"idle"`, `"reach"`, `"crouch"`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseLibrary {
+    poses: BTreeMap<String, Pose>,
+}
+
+impl PoseLibrary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a pose, returning the one it replaced
+    pub fn insert(&mut self, name: impl Into<String>, pose: Pose) -> Option<Pose> {
+        self.poses.insert(name.into(), pose)
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&Pose> {
+        self.poses.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Pose> {
+        self.poses.remove(name)
+    }
+
+    /// Pose names in sorted order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.poses.keys().map(String::as_str)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.poses.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.poses.is_empty()
+    }
+
+    /// Weighted blend of named poses (see [`Pose::blend`]); unknown names are ignored
+    pub fn blend<'a>(&self, weights: impl IntoIterator<Item = (&'a str, f32)>) -> Option<Pose> {
+        Pose::blend(
+            weights
+                .into_iter()
+                .filter_map(|(name, weight)| Some((self.get(name)?, weight))),
+        )
+    }
+}
+
+/// Local segment angles of a joint polyline (see [`RestPose`] for the convention)
+pub(crate) fn local_angles(joints: &[Vec2]) -> Vec<f32> {
+    let mut prev = 0.0;
+    joints
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            let world = (w[1] - w[0]).angle();
+            let local = if i == 0 {
+                world
+            } else {
+                wrap_angle(world - prev)
+            };
+            prev = world;
+            local
+        })
+        .collect()
+}
+
+/// Rebuild `joints[1..]` from `joints[0]` using local segment angles and lengths.
+/// Segments past the end of `angles` keep their current local angles, so the rest of the
+/// chain is carried along rigidly.
+pub(crate) fn place_joints(joints: &mut [Vec2], lengths: &[f32], angles: &[f32]) {
+    let padded;
+    let angles = if angles.len() < lengths.len() {
+        let mut full = local_angles(joints);
+        full[..angles.len()].copy_from_slice(angles);
+        padded = full;
+        &padded[..]
+    } else {
+        angles
+    };
+
+    let mut world = 0.0;
+    for (i, (&angle, &len)) in angles.iter().zip(lengths).enumerate() {
+        world += angle;
+        joints[i + 1] = joints[i] + Vec2::from_angle(world) * len;
+    }
+}
+
+impl Chain {
+    /// Local segment angles of the current pose (see [`RestPose`] for the convention)
+    pub fn local_angles(&self) -> Vec<f32> {
+        local_angles(&self.joints)
+    }
+
+    /// Rebuild joints from the base using local segment angles and current lengths;
+    /// segments without an angle keep theirs
+    pub fn set_local_angles(&mut self, angles: &[f32]) {
+        place_joints(&mut self.joints, &self.lengths, angles);
+    }
+
+    /// Snapshot of the current pose
+    pub fn capture_pose(&self) -> Pose {
+        Pose::from_joints(&self.joints)
+    }
+
+    /// Move joints into a stored pose, keeping the base and segment lengths; a pose with
+    /// fewer angles than segments leaves the remaining local angles as they are
+    pub fn apply_pose(&mut self, pose: &Pose) {
+        self.set_local_angles(&pose.angles);
+        self.reset_warm_start();
+    }
+
+    /// Joint positions of the weighted blend of `poses` for this chain's base and lengths,
+    /// without changing the chain; `None` if the weights sum to zero or less
+    pub fn blended_joints<'a>(
+        &self,
+        poses: impl IntoIterator<Item = (&'a Pose, f32)>,
+    ) -> Option<Vec<Vec2>> {
+        let pose = Pose::blend(poses)?;
+        let mut joints = self.joints.clone();
+        place_joints(&mut joints, &self.lengths, &pose.angles);
+        Some(joints)
+    }
+
+    /// Set or clear the pose the solver is biased toward
+    pub fn set_rest_pose(&mut self, rest: Option<RestPose>) {
+        self.rest = rest;
+    }
+
+    /// Use the current joint positions as the rest pose
+    pub fn capture_rest_pose(&mut self, stiffness: f32) {
+        self.rest = Some(RestPose::from_joints(&self.joints, stiffness));
+    }
+
+    #[inline]
+    pub fn rest_pose(&self) -> Option<&RestPose> {
+        self.rest.as_ref()
+    }
+
+    /// Spring one step back toward the rest pose, for frames without a target
+    pub fn relax(&mut self) {
+        self.apply_rest_bias();
+    }
+
+    /// Rotate every segment part of the way toward its rest angle
+    pub(crate) fn apply_rest_bias(&mut self) {
+        let Some(rest) = &self.rest else {
+            return;
+        };
+        let mut angles = local_angles(&self.joints);
+        for ((angle, &target), &stiffness) in
+            angles.iter_mut().zip(&rest.angles).zip(&rest.stiffness)
+        {
+            *angle += wrap_angle(target - *angle) * stiffness;
+        }
+        self.set_local_angles(&angles);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Pose, Vec2};
+
+    #[test]
+    fn short_pose_carries_the_rest_rigidly() {
+        let mut chain = Chain::with_lengths(Vec2::ZERO, vec![10.0; 4], 0.5, 10);
+        chain.set_local_angles(&[-1.0, 0.3, 0.3, 0.3]);
+        chain.apply_pose(&Pose::from_angles(vec![0.0, 0.5]));
+
+        for w in chain.joints.windows(2) {
+            assert!((w[0].distance(w[1]) - 10.0).abs() < 1e-4);
+        }
+        let angles = chain.local_angles();
+        for (angle, expected) in angles.iter().zip([0.0, 0.5, 0.3, 0.3]) {
+            assert!((angle - expected).abs() < 1e-4, "{angles:?}");
+        }
+    }
+}
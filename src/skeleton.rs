@@ -0,0 +1,150 @@
+//! Hierarchies of chains attached to joints of other chains.
+
+use crate::{Chain, SolveResult, Vec2};
+
+/// Handle to a chain inside a [`Skeleton`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChainId(usize);
+
+/// Named chains where each child's origin follows a joint of its parent.
+///
+/// Chains can only be attached to chains already in the skeleton, so insertion order is
+/// also a valid update order: parents are always solved before their children.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skeleton {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    name: String,
+    chain: Chain,
+    /// Parent chain and the joint of it this chain's origin sits on
+    parent: Option<(ChainId, usize)>,
+    target: Option<Vec2>,
+}
+
+impl Skeleton {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chain with a free origin
+    pub fn add_root(&mut self, name: impl Into<String>, chain: Chain) -> ChainId {
+        self.push(name.into(), chain, None)
+    }
+
+    /// Add a chain whose origin follows `joint` of `parent`
+    pub fn attach(
+        &mut self,
+        name: impl Into<String>,
+        mut chain: Chain,
+        parent: ChainId,
+        joint: usize,
+    ) -> ChainId {
+        let anchor = self.nodes[parent.0].chain.joints[joint];
+        chain.translate(anchor - chain.origin());
+        chain.reset_velocities();
+        self.push(name.into(), chain, Some((parent, joint)))
+    }
+
+    fn push(&mut self, name: String, chain: Chain, parent: Option<(ChainId, usize)>) -> ChainId {
+        self.nodes.push(Node {
+            name,
+            chain,
+            parent,
+            target: None,
+        });
+        ChainId(self.nodes.len() - 1)
+    }
+
+    /// Look up a chain by name
+    pub fn id(&self, name: &str) -> Option<ChainId> {
+        self.nodes.iter().position(|n| n.name == name).map(ChainId)
+    }
+
+    #[inline]
+    pub fn name(&self, id: ChainId) -> &str {
+        &self.nodes[id.0].name
+    }
+
+    #[inline]
+    pub fn chain(&self, id: ChainId) -> &Chain {
+        &self.nodes[id.0].chain
+    }
+
+    #[inline]
+    pub fn chain_mut(&mut self, id: ChainId) -> &mut Chain {
+        &mut self.nodes[id.0].chain
+    }
+
+    /// Parent chain and joint index, `None` for roots
+    #[inline]
+    pub fn parent(&self, id: ChainId) -> Option<(ChainId, usize)> {
+        self.nodes[id.0].parent
+    }
+
+    /// Set or clear the end-effector target a chain is solved toward
+    pub fn set_target(&mut self, id: ChainId, target: Option<Vec2>) {
+        self.nodes[id.0].target = target;
+    }
+
+    /// All chains in update order (parents before children)
+    pub fn iter(&self) -> impl Iterator<Item = (ChainId, &str, &Chain)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (ChainId(i), n.name.as_str(), &n.chain))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Move every child onto its parent joint and solve chains that have a target,
+    /// parents first. Chains without a target follow their parent rigidly, except
+    /// simulated ones ([`Chain::verlet`]), which are dragged by their origin and left for
+    /// [`Chain::simulate`] to swing.
+    /// Returns one result per solved chain.
+    pub fn solve(&mut self) -> Vec<(ChainId, SolveResult)> {
+        let mut results = Vec::new();
+        for i in 0..self.nodes.len() {
+            self.follow_parent(i);
+            let node = &mut self.nodes[i];
+            if let Some(target) = node.target {
+                results.push((ChainId(i), node.chain.solve(target)));
+            }
+        }
+        results
+    }
+
+    /// Move every child onto its parent joint without solving
+    pub fn update_origins(&mut self) {
+        for i in 0..self.nodes.len() {
+            self.follow_parent(i);
+        }
+    }
+
+    fn follow_parent(&mut self, i: usize) {
+        let Some((parent, joint)) = self.nodes[i].parent else {
+            return;
+        };
+        let anchor = self.nodes[parent.0].chain.joints[joint];
+        let node = &mut self.nodes[i];
+        if node.target.is_some() || node.chain.verlet.is_some() {
+            node.chain.set_origin(anchor);
+        } else {
+            let offset = anchor - node.chain.origin();
+            node.chain.translate(offset);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Enumerating several distinct poses that reach the same target.
+
+use crate::{Chain, Vec2};
+
+/// Bend per segment (radians) of the arc-shaped seed poses, tried in both directions
+const SEED_BENDS: [f32; 4] = [0.15, 0.4, 0.8, 1.3];
+
+/// One valid configuration found by [`Chain::solutions`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution {
+    pub joints: Vec<Vec2>,
+    /// Distance from the end effector to the target
+    pub error: f32,
+    /// Root-mean-square joint distance from the chain's current pose
+    pub distance: f32,
+}
+
+impl Chain {
+    /// Up to `count` distinct poses reaching `target`, closest to the current pose first.
+    ///
+    /// FABRIK is seeded from the current pose, its mirror image across the base-target
+    /// line and arcs bending either way, so both bend families show up when they exist.
+    /// Only converged poses are returned; the chain itself is left untouched.
+    pub fn solutions(&self, target: impl Into<Vec2>, count: usize) -> Vec<Solution> {
+        let target = target.into();
+        let base = self.joints[0];
+        let mut found: Vec<Solution> = Vec::new();
+        let distinct = (self.total_length * 0.02).max(self.tolerance * 4.0);
+
+        for seed in self.seed_poses(target) {
+            let mut chain = self.clone();
+            chain.reset_warm_start();
+            chain.joints = seed;
+            chain.joints[0] = base;
+            let result = chain.solve(target);
+            if !result.converged() {
+                continue;
+            }
+
+            let duplicate = found
+                .iter()
+                .any(|s| max_joint_distance(&s.joints, &chain.joints) < distinct);
+            if !duplicate {
+                found.push(Solution {
+                    distance: rms_joint_distance(&self.joints, &chain.joints),
+                    error: result.error,
+                    joints: chain.joints,
+                });
+            }
+        }
+
+        found.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        found.truncate(count);
+        found
+    }
+
+    /// Initial poses: current, mirrored, then arcs toward the target bending both ways
+    fn seed_poses(&self, target: Vec2) -> Vec<Vec<Vec2>> {
+        let base = self.joints[0];
+        let axis = (target - base).normalize();
+        let mirror = |p: Vec2| {
+            let rel = p - base;
+            base + axis * (2.0 * rel.dot(axis)) - rel
+        };
+
+        let mut seeds = vec![
+            self.joints.clone(),
+            self.joints.iter().map(|&p| mirror(p)).collect(),
+        ];
+
+        let heading = axis.angle();
+        let segments = self.lengths.len() as f32;
+        for bend in SEED_BENDS {
+            for bend in [bend, -bend] {
+                let mut angles = vec![bend; self.lengths.len()];
+                if let Some(first) = angles.first_mut() {
+                    *first = heading - bend * (segments - 1.0) / 2.0;
+                }
+                let mut chain = self.clone();
+                chain.set_local_angles(&angles);
+                seeds.push(chain.joints);
+            }
+        }
+        seeds
+    }
+}
+
+fn max_joint_distance(a: &[Vec2], b: &[Vec2]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(&p, &q)| p.distance_squared(q))
+        .fold(0.0, f32::max)
+        .sqrt()
+}
+
+fn rms_joint_distance(a: &[Vec2], b: &[Vec2]) -> f32 {
+    let sum: f32 = a.iter().zip(b).map(|(&p, &q)| p.distance_squared(q)).sum();
+    (sum / a.len().max(1) as f32).sqrt()
+}
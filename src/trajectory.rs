@@ -0,0 +1,109 @@
+//! Smooth joint-space motion between two poses of a chain.
+
+use crate::math::wrap_angle;
+use crate::pose::{local_angles, place_joints};
+use crate::{Chain, Vec2};
+
+/// Time profile used to blend from the start pose to the end pose
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Constant joint velocity
+    Linear,
+    /// Cubic ease with zero velocity at both ends
+    Cubic,
+    /// Quintic ease with zero velocity and acceleration at both ends
+    #[default]
+    MinimumJerk,
+}
+
+impl Interpolation {
+    /// Blend fraction at normalized time `t` in `[0, 1]`
+    #[inline]
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::Cubic => t * t * (3.0 - 2.0 * t),
+            Self::MinimumJerk => t * t * t * (10.0 + t * (6.0 * t - 15.0)),
+        }
+    }
+}
+
+/// One sampled pose along a trajectory
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrajectorySample {
+    /// Seconds since the start of the motion
+    pub time: f32,
+    pub joints: Vec<Vec2>,
+}
+
+impl Chain {
+    /// Sample a motion from pose `from` to pose `to` (joint positions of this chain).
+    ///
+    /// Local segment angles are interpolated along the shortest way round, so every
+    /// sample keeps the segment lengths exactly; telescopic segments blend between their
+    /// lengths in both poses and the base moves in a straight line. Samples are taken
+    /// `rate` times per second over `duration` seconds, both ends included.
+    pub fn trajectory(
+        &self,
+        from: &[Vec2],
+        to: &[Vec2],
+        interpolation: Interpolation,
+        duration: f32,
+        rate: f32,
+    ) -> Vec<TrajectorySample> {
+        let n = self.joints.len();
+        assert!(
+            from.len() == n && to.len() == n,
+            "poses must have one position per joint of the chain"
+        );
+
+        let start = local_angles(from);
+        let delta: Vec<f32> = start
+            .iter()
+            .zip(local_angles(to))
+            .map(|(&a, b)| wrap_angle(b - a))
+            .collect();
+        let lengths_from = pose_lengths(from);
+        let lengths_to = pose_lengths(to);
+
+        let steps = (duration * rate).ceil().max(1.0) as usize;
+        let mut angles = start.clone();
+        let mut lengths = lengths_from.clone();
+
+        (0..=steps)
+            .map(|step| {
+                let t = step as f32 / steps as f32;
+                let s = interpolation.ease(t);
+                for i in 0..angles.len() {
+                    angles[i] = start[i] + delta[i] * s;
+                    lengths[i] = lengths_from[i] + (lengths_to[i] - lengths_from[i]) * s;
+                }
+
+                let mut joints = vec![from[0].lerp(to[0], s); n];
+                place_joints(&mut joints, &lengths, &angles);
+                TrajectorySample {
+                    time: t * duration,
+                    joints,
+                }
+            })
+            .collect()
+    }
+
+    /// Motion from the current pose to `to`, see [`trajectory`](Self::trajectory)
+    pub fn trajectory_to(
+        &self,
+        to: &[Vec2],
+        interpolation: Interpolation,
+        duration: f32,
+        rate: f32,
+    ) -> Vec<TrajectorySample> {
+        self.trajectory(&self.joints, to, interpolation, duration, rate)
+    }
+}
+
+fn pose_lengths(joints: &[Vec2]) -> Vec<f32> {
+    joints.windows(2).map(|w| w[0].distance(w[1])).collect()
+}
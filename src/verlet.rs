@@ -0,0 +1,86 @@
+//! Verlet simulation layer: inertia, gravity and damping under FABRIK constraints.
+
+use crate::{Chain, SolveResult, SolveRun, Vec2};
+
+/// Physics settings and state for [`Chain::simulate`], see [`Chain::verlet`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Verlet {
+    /// Acceleration in units per second squared; screen space (`y` down) by default
+    pub gravity: Vec2,
+    /// Fraction of each joint's velocity lost per step, in `[0, 1]`
+    pub damping: f32,
+    /// Joint positions one step ago; velocity is implied by the difference
+    previous: Vec<Vec2>,
+}
+
+impl Default for Verlet {
+    fn default() -> Self {
+        Self::new(Vec2::new(0.0, 980.0), 0.02)
+    }
+}
+
+impl Verlet {
+    pub fn new(gravity: Vec2, damping: f32) -> Self {
+        Self {
+            gravity,
+            damping,
+            previous: Vec::new(),
+        }
+    }
+
+    /// Move the remembered positions along with a rigid translation of the chain
+    pub(crate) fn shift(&mut self, offset: Vec2) {
+        for p in &mut self.previous {
+            *p += offset;
+        }
+    }
+}
+
+impl Chain {
+    /// Advance the simulation by `dt` seconds.
+    ///
+    /// Joints keep their momentum and fall under gravity, then the FABRIK constraints are
+    /// applied: with a target the chain is solved toward it (joint goals included),
+    /// without one the segments are only pulled back to length from the origin, so the
+    /// chain hangs and swings freely. Without [`Chain::verlet`] this is a plain solve.
+    pub fn simulate(&mut self, dt: f32, target: Option<Vec2>) -> Option<SolveResult> {
+        let Some(verlet) = self.verlet.as_mut() else {
+            return target.map(|target| self.solve(target));
+        };
+        if verlet.previous.len() != self.joints.len() {
+            verlet.previous = self.joints.clone();
+        }
+
+        let keep = 1.0 - verlet.damping.clamp(0.0, 1.0);
+        let accel = verlet.gravity * (dt * dt);
+        for (joint, prev) in self.joints.iter_mut().zip(&mut verlet.previous).skip(1) {
+            let velocity = (*joint - *prev) * keep;
+            *prev = *joint;
+            *joint += velocity + accel;
+        }
+        verlet.previous[0] = self.joints[0];
+
+        // The pose no longer matches the last solve
+        self.reset_warm_start();
+        let last = self.joints.len() - 1;
+        match target {
+            Some(target) => {
+                let mut run = SolveRun::new(self.time_budget);
+                self.solve_pinned(&mut run, target, true);
+                Some(run.finish(self, target))
+            }
+            None => {
+                self.backward_reach(0, last, self.origin);
+                None
+            }
+        }
+    }
+
+    /// Stop all motion, keeping the current pose
+    pub fn reset_velocities(&mut self) {
+        if let Some(verlet) = self.verlet.as_mut() {
+            verlet.previous = self.joints.clone();
+        }
+    }
+}
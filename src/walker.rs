@@ -0,0 +1,237 @@
+//! Procedural walking: legs that step to keep up with a moving body.
+
+use std::f32::consts::PI;
+
+use crate::{Chain, Interpolation, SolveResult, Terrain, Transform2, Vec2};
+
+/// One leg of a [`Walker`]: a chain hanging from a hip on the body
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Leg {
+    pub chain: Chain,
+    /// Hip position in body space
+    pub mount: Vec2,
+    /// Resting foot position in body space
+    pub home: Vec2,
+    /// Gait group; legs of one group lift off together, groups take turns
+    pub group: usize,
+    foot: Vec2,
+    step: Option<Step>,
+}
+
+/// A foot in the air, moving from `from` to `to`
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Step {
+    from: Vec2,
+    to: Vec2,
+    elapsed: f32,
+}
+
+impl Leg {
+    /// Where the foot is: planted, or somewhere along its step arc
+    #[inline]
+    pub fn foot(&self) -> Vec2 {
+        self.foot
+    }
+
+    #[inline]
+    pub fn is_stepping(&self) -> bool {
+        self.step.is_some()
+    }
+
+    /// Where the foot is heading, if stepping
+    #[inline]
+    pub fn step_target(&self) -> Option<Vec2> {
+        self.step.map(|s| s.to)
+    }
+}
+
+/// A body with several legs and a gait controller.
+///
+/// Move the body with [`Walker::body`] (or [`Walker::set_body`]) and call
+/// [`Walker::update`] every frame: planted feet stay put until they drift more than
+/// `step_distance` from their home spot, then their whole gait group steps along an arc
+/// while the other groups hold the ground.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Walker {
+    pub body: Transform2,
+    pub legs: Vec<Leg>,
+    /// How far a planted foot may drift from its home spot before it steps
+    pub step_distance: f32,
+    /// Seconds a step takes
+    pub step_duration: f32,
+    /// Peak lift of the step arc
+    pub step_height: f32,
+    /// Fraction of the drift a step overshoots past home, so feet land ahead of the motion
+    pub overshoot: f32,
+    /// Direction feet lift toward; screen space (`y` down) by default
+    pub up: Vec2,
+    pub interpolation: Interpolation,
+    /// Ground the feet are planted on, if any
+    pub terrain: Option<Terrain>,
+    /// Group that stepped last; the next one gets the first chance
+    last_group: usize,
+    /// Body position at the previous update and the velocity measured from it
+    last_position: Vec2,
+    velocity: Vec2,
+}
+
+impl Walker {
+    pub fn new(body: Transform2) -> Self {
+        Self {
+            body,
+            legs: Vec::new(),
+            step_distance: 40.0,
+            step_duration: 0.2,
+            step_height: 20.0,
+            overshoot: 0.5,
+            up: -Vec2::Y,
+            interpolation: Interpolation::MinimumJerk,
+            terrain: None,
+            last_group: 0,
+            last_position: body.translation,
+            velocity: Vec2::ZERO,
+        }
+    }
+
+    /// Add a leg; the chain is moved onto its hip and the foot planted at home.
+    /// Returns the leg's index.
+    pub fn add_leg(&mut self, mut chain: Chain, mount: Vec2, home: Vec2, group: usize) -> usize {
+        let hip = self.body.transform_point(mount);
+        chain.translate(hip - chain.origin());
+        chain.reset_velocities();
+        let foot = self.ground(self.body.transform_point(home));
+        self.legs.push(Leg {
+            chain,
+            mount,
+            home,
+            group,
+            foot,
+            step: None,
+        });
+        self.legs.len() - 1
+    }
+
+    #[inline]
+    pub fn set_body(&mut self, body: Transform2) {
+        self.body = body;
+    }
+
+    /// Whether any leg is mid-step
+    pub fn is_stepping(&self) -> bool {
+        self.legs.iter().any(Leg::is_stepping)
+    }
+
+    /// Body velocity measured over the last [`Walker::update`]
+    #[inline]
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Number of gait groups in use
+    pub fn group_count(&self) -> usize {
+        self.legs.iter().map(|l| l.group + 1).max().unwrap_or(0)
+    }
+
+    /// Advance steps by `dt` seconds, start new ones where needed and solve every leg
+    pub fn update(&mut self, dt: f32) -> Vec<SolveResult> {
+        if dt > 0.0 {
+            self.velocity = (self.body.translation - self.last_position) / dt;
+        }
+        self.last_position = self.body.translation;
+        self.advance_steps(dt);
+        if !self.is_stepping() {
+            self.trigger_steps();
+        }
+
+        let body = self.body;
+        let terrain = self.terrain.as_ref();
+        self.legs
+            .iter_mut()
+            .map(|leg| {
+                let hip = body.transform_point(leg.mount);
+                if leg.chain.verlet.is_some() {
+                    leg.chain.set_origin(hip);
+                } else {
+                    leg.chain.translate(hip - leg.chain.origin());
+                }
+                match terrain {
+                    Some(terrain) if !leg.is_stepping() => leg.chain.solve_foot(leg.foot, terrain),
+                    _ => leg.chain.solve(leg.foot),
+                }
+            })
+            .collect()
+    }
+
+    /// Move airborne feet along their arcs, planting those that arrive
+    fn advance_steps(&mut self, dt: f32) {
+        let (duration, height, up) = (
+            self.step_duration.max(f32::EPSILON),
+            self.step_height,
+            self.up,
+        );
+        let interpolation = self.interpolation;
+        for leg in &mut self.legs {
+            let Some(step) = leg.step.as_mut() else {
+                continue;
+            };
+            step.elapsed += dt;
+            let t = (step.elapsed / duration).min(1.0);
+            let lift = up * (height * (PI * t).sin());
+            leg.foot = step.from.lerp(step.to, interpolation.ease(t)) + lift;
+            if t >= 1.0 {
+                leg.foot = step.to;
+                leg.step = None;
+            }
+        }
+    }
+
+    /// Lift the first group, starting after the one that stepped last, with a foot past
+    /// `step_distance` from home. Every leg of that group that has drifted at all steps,
+    /// aiming past where its home spot will be when it lands at the current body velocity.
+    fn trigger_steps(&mut self) {
+        let groups = self.group_count();
+        let due = (1..=groups)
+            .map(|k| (self.last_group + k) % groups)
+            .find(|&g| {
+                self.legs
+                    .iter()
+                    .any(|l| l.group == g && self.drift(l).length() > self.step_distance)
+            });
+        let Some(group) = due else {
+            return;
+        };
+
+        self.last_group = group;
+        for i in 0..self.legs.len() {
+            let leg = &self.legs[i];
+            let drift = self.drift(leg);
+            if leg.group != group || drift == Vec2::ZERO {
+                continue;
+            }
+            let home = self.body.transform_point(leg.home) + self.velocity * self.step_duration;
+            let to = self.ground(home + drift * self.overshoot);
+            let leg = &mut self.legs[i];
+            leg.step = Some(Step {
+                from: leg.foot,
+                to,
+                elapsed: 0.0,
+            });
+        }
+    }
+
+    /// How far the leg's home spot has moved away from its planted foot
+    fn drift(&self, leg: &Leg) -> Vec2 {
+        self.body.transform_point(leg.home) - leg.foot
+    }
+
+    /// Drop a point onto the terrain, if there is one under it
+    fn ground(&self, p: Vec2) -> Vec2 {
+        self.terrain
+            .as_ref()
+            .and_then(|t| t.height_at(p.x))
+            .map_or(p, |y| Vec2::new(p.x, y))
+    }
+}
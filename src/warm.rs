@@ -0,0 +1,126 @@
+//! Frame-to-frame reuse: skipping repeated solves and predicting the next pose.
+
+use crate::{Chain, SolveResult, SolveStatus, Vec2};
+
+/// What the previous [`Chain::solve`] was asked, the public solver settings it ran with
+/// and where it left the chain
+#[derive(Debug, Clone)]
+pub(crate) struct LastSolve {
+    pub target: Vec2,
+    pub joints: Vec<Vec2>,
+    pub lengths: Vec<f32>,
+    pub tolerance: f32,
+    pub relative_tolerance: f32,
+    pub max_iterations: usize,
+    pub soft_reach: f32,
+    pub result: SolveResult,
+}
+
+impl Chain {
+    /// Forget the previous solve, so the next one neither skips nor predicts
+    pub fn reset_warm_start(&mut self) {
+        self.last_solve = None;
+    }
+
+    /// Previous result, if solving `target` again could not change the pose: same target,
+    /// joints, lengths and solver settings, a final status, a mobile base with nowhere left
+    /// to go, and nothing (sag, rest pose) that moves joints on every call
+    pub(crate) fn cached_result(&self, target: Vec2) -> Option<SolveResult> {
+        let last = self.last_solve.as_ref()?;
+        let base = self.joints[0];
+        let parked = self
+            .mobile_base
+            .is_none_or(|mobile| mobile.step(base, target, self.total_length) == base);
+        let settled = parked
+            && matches!(
+                last.result.status,
+                SolveStatus::Converged | SolveStatus::Unreachable
+            );
+        let unchanged = last.target == target
+            && last.joints == self.joints
+            && last.lengths == self.lengths
+            && last.tolerance == self.tolerance
+            && last.relative_tolerance == self.relative_tolerance
+            && last.max_iterations == self.max_iterations
+            && last.soft_reach == self.soft_reach;
+        let passive = self.sag_per_solve == Vec2::ZERO && self.rest.is_none();
+
+        (settled && unchanged && passive).then_some(SolveResult {
+            iterations: 0,
+            ..last.result
+        })
+    }
+
+    /// Shift the pose by the target's motion since the previous solve, spread from nothing
+    /// at the base to all of it at the end effector, so FABRIK starts near the answer.
+    /// Returns whether joints moved (segment lengths then need at least one pass).
+    pub(crate) fn predict(&mut self, target: Vec2) -> bool {
+        let Some(last) = &self.last_solve else {
+            return false;
+        };
+        let delta = target - last.target;
+        if delta == Vec2::ZERO {
+            return false;
+        }
+        let last_joint = self.joints.len() - 1;
+        for (i, joint) in self.joints.iter_mut().enumerate().skip(1) {
+            *joint += delta * (i as f32 / last_joint as f32);
+        }
+        true
+    }
+
+    pub(crate) fn record_solve(&mut self, target: Vec2, result: SolveResult) {
+        self.last_solve = Some(LastSolve {
+            target,
+            joints: self.joints.clone(),
+            lengths: self.lengths.clone(),
+            tolerance: self.tolerance,
+            relative_tolerance: self.relative_tolerance,
+            max_iterations: self.max_iterations,
+            soft_reach: self.soft_reach,
+            result,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, ChainConfig, SolveStatus, Vec2};
+
+    fn solved_chain() -> (Chain, Vec2) {
+        let mut chain = Chain::new(Vec2::ZERO, &ChainConfig::default());
+        let target = Vec2::new(120.0, -200.0);
+        assert!(chain.solve(target).converged());
+        (chain, target)
+    }
+
+    #[test]
+    fn repeat_solve_is_skipped() {
+        let (mut chain, target) = solved_chain();
+        let result = chain.solve(target);
+        assert_eq!(result.status, SolveStatus::Converged);
+        assert_eq!(result.iterations, 0);
+    }
+
+    #[test]
+    fn edited_joints_are_repaired() {
+        let (mut chain, target) = solved_chain();
+        chain.joints[2] += Vec2::new(0.0, 30.0);
+        let result = chain.solve(target);
+        assert!(result.iterations > 0);
+        for (w, &len) in chain.joints.windows(2).zip(&chain.lengths) {
+            assert!((w[0].distance(w[1]) - len).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn changed_settings_solve_again() {
+        let (mut chain, target) = solved_chain();
+        chain.soft_reach = 200.0;
+        assert!(chain.solve(target).iterations > 0);
+
+        let (mut chain, target) = solved_chain();
+        chain.lengths[0] = 40.0;
+        assert!(chain.solve(target).iterations > 0);
+    }
+}
@@ -0,0 +1,147 @@
+//! Reachable workspace of a chain's end effector.
+
+use std::f32::consts::PI;
+
+use crate::pose::place_joints;
+use crate::{Chain, Vec2};
+
+/// Occupancy grid of end-effector positions, see [`Chain::sample_workspace`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkspaceGrid {
+    /// World position of the corner of cell `(0, 0)`
+    pub origin: Vec2,
+    pub cell_size: f32,
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<bool>,
+}
+
+impl WorkspaceGrid {
+    fn new(origin: Vec2, cell_size: f32, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            cell_size,
+            width,
+            height,
+            cells: vec![false; width * height],
+        }
+    }
+
+    /// Cell containing a world position, if inside the grid
+    pub fn cell_at(&self, p: Vec2) -> Option<(usize, usize)> {
+        let local = (p - self.origin) / self.cell_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (local.x as usize, local.y as usize);
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Whether cell `(x, y)` was reached
+    #[inline]
+    pub fn is_reachable(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    /// Whether the cell containing `p` was reached
+    pub fn contains(&self, p: Vec2) -> bool {
+        self.cell_at(p)
+            .is_some_and(|(x, y)| self.is_reachable(x, y))
+    }
+
+    /// World-space center of every reached cell
+    pub fn reachable_cells(&self) -> impl Iterator<Item = Vec2> + '_ {
+        (0..self.cells.len())
+            .filter(|&i| self.cells[i])
+            .map(|i| self.cell_center(i % self.width, i / self.width))
+    }
+
+    #[inline]
+    pub fn cell_center(&self, x: usize, y: usize) -> Vec2 {
+        self.origin + Vec2::new(x as f32 + 0.5, y as f32 + 0.5) * self.cell_size
+    }
+
+    fn mark(&mut self, p: Vec2) {
+        if let Some((x, y)) = self.cell_at(p) {
+            self.cells[y * self.width + x] = true;
+        }
+    }
+}
+
+impl Chain {
+    /// Inner and outer radius of the annulus around the base reachable by an unconstrained
+    /// chain. The inner radius is non-zero only when one segment is longer than all the
+    /// others together (telescopic segments counted at their extremes).
+    pub fn reach_radii(&self) -> (f32, f32) {
+        let outer = self.total_length;
+        let inner = (0..self.lengths.len())
+            .map(|i| {
+                let (min, max) = self.length_range(i);
+                min - (outer - max)
+            })
+            .fold(0.0, f32::max);
+        (inner, outer)
+    }
+
+    /// Whether `p` lies in the unconstrained reach annulus
+    pub fn can_reach(&self, p: impl Into<Vec2>) -> bool {
+        let (inner, outer) = self.reach_radii();
+        let dist = self.joints[0].distance(p.into());
+        dist >= inner && dist <= outer
+    }
+
+    /// Sample random poses and mark the cells their end effectors land in.
+    ///
+    /// Poses are drawn uniformly in joint-angle space (telescopic segments anywhere in their
+    /// range) around the current base. `valid` sees every pose and can reject it, e.g. for
+    /// joint limits or when a segment crosses level geometry. The grid covers the outer
+    /// reach circle; sampling is deterministic. Panics unless `cell_size` is positive.
+    pub fn sample_workspace(
+        &self,
+        cell_size: f32,
+        samples: usize,
+        mut valid: impl FnMut(&[Vec2]) -> bool,
+    ) -> WorkspaceGrid {
+        assert!(
+            cell_size > 0.0,
+            "cell_size must be positive, got {cell_size}"
+        );
+        let base = self.joints[0];
+        let reach = self.total_length;
+        let cells = ((2.0 * reach / cell_size).ceil() as usize).max(1);
+        let corner = base - Vec2::new(reach, reach);
+        let mut grid = WorkspaceGrid::new(corner, cell_size, cells, cells);
+
+        let mut rng = XorShift(0x9E37_79B9);
+        let mut joints = self.joints.clone();
+        let mut lengths = self.lengths.clone();
+        let mut angles = vec![0.0; self.lengths.len()];
+
+        for _ in 0..samples {
+            for (i, angle) in angles.iter_mut().enumerate() {
+                *angle = (rng.next_f32() * 2.0 - 1.0) * PI;
+                let (min, max) = self.length_range(i);
+                lengths[i] = min + (max - min) * rng.next_f32();
+            }
+            place_joints(&mut joints, &lengths, &angles);
+            if valid(&joints) {
+                grid.mark(*joints.last().unwrap());
+            }
+        }
+        grid
+    }
+}
+
+/// Small deterministic generator so sampling needs no dependency
+struct XorShift(u32);
+
+impl XorShift {
+    #[inline]
+    fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32
+    }
+}